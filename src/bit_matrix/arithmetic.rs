@@ -0,0 +1,83 @@
+use super::core::BitMatrix;
+use crate::vector::Vector;
+use core::array;
+use core::ops::{Add, Mul};
+
+impl<const M: usize, const N: usize> Add for &BitMatrix<M, N> {
+    type Output = BitMatrix<M, N>;
+    fn add(self, rhs: Self) -> Self::Output {
+        BitMatrix {
+            words: self
+                .words
+                .iter()
+                .zip(rhs.words.iter())
+                .map(|(a, b)| a ^ b)
+                .collect(),
+        }
+    }
+}
+
+impl<const M: usize, const N: usize> Add for BitMatrix<M, N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<const M: usize, const K: usize, const N: usize> Mul<&BitMatrix<K, N>> for &BitMatrix<M, K> {
+    type Output = BitMatrix<M, N>;
+    fn mul(self, rhs: &BitMatrix<K, N>) -> Self::Output {
+        // Entry (i, j) is the parity of the AND of row i and column j.
+        let rhs_t = rhs.transpose();
+        let mut result = BitMatrix::zeros();
+        for i in 0..M {
+            for j in 0..N {
+                let ones: u32 = self
+                    .row(i)
+                    .iter()
+                    .zip(rhs_t.row(j))
+                    .map(|(a, b)| (a & b).count_ones())
+                    .sum();
+                result.set(i, j, ones % 2 == 1);
+            }
+        }
+        result
+    }
+}
+
+impl<const M: usize, const K: usize, const N: usize> Mul<BitMatrix<K, N>> for BitMatrix<M, K> {
+    type Output = BitMatrix<M, N>;
+    fn mul(self, rhs: BitMatrix<K, N>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<const M: usize, const N: usize> Mul<Vector<bool, N>> for &BitMatrix<M, N> {
+    type Output = Vector<bool, M>;
+    fn mul(self, rhs: Vector<bool, N>) -> Self::Output {
+        Vector {
+            data: array::from_fn(|i| {
+                (0..N).filter(|&j| rhs.data[j] && self.get(i, j)).count() % 2 == 1
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn test_add_is_xor() {
+        let a = BitMatrix::from(Matrix::new([[1u8, 1], [0, 1]]));
+        let b = BitMatrix::from(Matrix::new([[1u8, 0], [1, 1]]));
+        assert_eq!(a + b, BitMatrix::from(Matrix::new([[0u8, 1], [1, 0]])));
+    }
+
+    #[test]
+    fn test_mul() {
+        let a = BitMatrix::from(Matrix::new([[1u8, 1], [0, 1]]));
+        assert_eq!(&a * &a, BitMatrix::identity());
+    }
+}
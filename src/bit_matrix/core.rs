@@ -0,0 +1,137 @@
+use crate::matrix::Matrix;
+
+/// A matrix over GF(2), each row packed into `u64` words. Bits past column `N`
+/// are always zero.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitMatrix<const M: usize, const N: usize> {
+    pub(crate) words: Vec<u64>,
+}
+
+impl<const M: usize, const N: usize> BitMatrix<M, N> {
+    pub(crate) const WORDS: usize = N.div_ceil(64);
+
+    pub fn zeros() -> Self {
+        Self {
+            words: vec![0; M * Self::WORDS],
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        assert!(row < M && col < N, "BitMatrix index out of bounds");
+        (self.words[row * Self::WORDS + col / 64] >> (col % 64)) & 1 == 1
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: bool) {
+        assert!(row < M && col < N, "BitMatrix index out of bounds");
+        let word = &mut self.words[row * Self::WORDS + col / 64];
+        if value {
+            *word |= 1 << (col % 64);
+        } else {
+            *word &= !(1 << (col % 64));
+        }
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < M && b < M, "BitMatrix row index out of bounds");
+        for k in 0..Self::WORDS {
+            self.words.swap(a * Self::WORDS + k, b * Self::WORDS + k);
+        }
+    }
+
+    /// XORs row `source` into row `target`.
+    pub fn add_row(&mut self, target: usize, source: usize) {
+        assert!(
+            target < M && source < M,
+            "BitMatrix row index out of bounds"
+        );
+        for k in 0..Self::WORDS {
+            let value = self.words[source * Self::WORDS + k];
+            self.words[target * Self::WORDS + k] ^= value;
+        }
+    }
+
+    pub(crate) fn row(&self, row: usize) -> &[u64] {
+        &self.words[row * Self::WORDS..(row + 1) * Self::WORDS]
+    }
+}
+
+impl<const M: usize> BitMatrix<M, M> {
+    pub fn identity() -> Self {
+        let mut result = Self::zeros();
+        for i in 0..M {
+            result.set(i, i, true);
+        }
+        result
+    }
+}
+
+impl<const M: usize, const N: usize> From<Matrix<bool, M, N>> for BitMatrix<M, N> {
+    fn from(matrix: Matrix<bool, M, N>) -> Self {
+        let mut result = Self::zeros();
+        for i in 0..M {
+            for j in 0..N {
+                result.set(i, j, matrix[i][j]);
+            }
+        }
+        result
+    }
+}
+
+/// Entries are reduced modulo 2.
+impl<const M: usize, const N: usize> From<Matrix<u8, M, N>> for BitMatrix<M, N> {
+    fn from(matrix: Matrix<u8, M, N>) -> Self {
+        let mut result = Self::zeros();
+        for i in 0..M {
+            for j in 0..N {
+                result.set(i, j, matrix[i][j] & 1 == 1);
+            }
+        }
+        result
+    }
+}
+
+impl<const M: usize, const N: usize> From<&BitMatrix<M, N>> for Matrix<bool, M, N> {
+    fn from(bits: &BitMatrix<M, N>) -> Self {
+        let mut result = Matrix::zeros();
+        for i in 0..M {
+            for j in 0..N {
+                result[i][j] = bits.get(i, j);
+            }
+        }
+        result
+    }
+}
+
+impl<const M: usize, const N: usize> From<&BitMatrix<M, N>> for Matrix<u8, M, N> {
+    fn from(bits: &BitMatrix<M, N>) -> Self {
+        let mut result = Matrix::zeros();
+        for i in 0..M {
+            for j in 0..N {
+                result[i][j] = bits.get(i, j) as u8;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_set() {
+        let mut m: BitMatrix<2, 70> = BitMatrix::zeros();
+        m.set(1, 65, true);
+        assert!(m.get(1, 65));
+        assert!(!m.get(0, 65));
+        m.set(1, 65, false);
+        assert_eq!(m, BitMatrix::zeros());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let m = Matrix::new([[1u8, 0, 1], [0, 1, 1]]);
+        let bits = BitMatrix::from(m);
+        assert_eq!(Matrix::<u8, 2, 3>::from(&bits), m);
+    }
+}
@@ -0,0 +1,28 @@
+use super::core::BitMatrix;
+use core::fmt;
+use core::fmt::{Debug, Formatter};
+
+impl<const M: usize, const N: usize> Debug for BitMatrix<M, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rows: Vec<String> = (0..M)
+            .map(|i| {
+                (0..N)
+                    .map(|j| if self.get(i, j) { '1' } else { '0' })
+                    .collect()
+            })
+            .collect();
+        f.debug_struct("BitMatrix").field("rows", &rows).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn test_debug() {
+        let m = BitMatrix::from(Matrix::new([[true, false], [false, true]]));
+        assert_eq!(format!("{:?}", m), "BitMatrix { rows: [\"10\", \"01\"] }");
+    }
+}
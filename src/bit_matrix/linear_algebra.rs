@@ -0,0 +1,96 @@
+use super::core::BitMatrix;
+use crate::vector::Vector;
+
+impl<const M: usize, const N: usize> BitMatrix<M, N> {
+    pub fn transpose(&self) -> BitMatrix<N, M> {
+        let mut result = BitMatrix::zeros();
+        for i in 0..M {
+            for j in 0..N {
+                if self.get(i, j) {
+                    result.set(j, i, true);
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns the pivot column of each nonzero row.
+    fn reduce(&mut self, rhs: &mut [bool; M]) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        for col in 0..N {
+            let row = pivots.len();
+            if row == M {
+                break;
+            }
+            let pivot = match (row..M).find(|&j| self.get(j, col)) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            self.swap_rows(row, pivot);
+            rhs.swap(row, pivot);
+            for j in 0..M {
+                if j != row && self.get(j, col) {
+                    self.add_row(j, row);
+                    rhs[j] ^= rhs[row];
+                }
+            }
+            pivots.push(col);
+        }
+        pivots
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().reduce(&mut [false; M]).len()
+    }
+
+    /// A basis of the vectors `x` with `self * x = 0`.
+    pub fn nullspace(&self) -> Vec<Vector<bool, N>> {
+        let mut reduced = self.clone();
+        let pivots = reduced.reduce(&mut [false; M]);
+        (0..N)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut x = [false; N];
+                x[free] = true;
+                for (row, &pivot) in pivots.iter().enumerate() {
+                    x[pivot] = reduced.get(row, free);
+                }
+                Vector { data: x }
+            })
+            .collect()
+    }
+
+    /// Sets every free variable to zero.
+    pub fn solve(&self, b: &Vector<bool, M>) -> Option<Vector<bool, N>> {
+        let mut reduced = self.clone();
+        let mut rhs = b.data;
+        let pivots = reduced.reduce(&mut rhs);
+        if rhs[pivots.len()..].iter().any(|&bit| bit) {
+            return None;
+        }
+        let mut x = [false; N];
+        for (row, &pivot) in pivots.iter().enumerate() {
+            x[pivot] = rhs[row];
+        }
+        Some(Vector { data: x })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn test_transpose() {
+        let m = BitMatrix::from(Matrix::new([[1u8, 0, 1], [0, 1, 1]]));
+        let expected = BitMatrix::from(Matrix::new([[1u8, 0], [0, 1], [1, 1]]));
+        assert_eq!(m.transpose(), expected);
+    }
+
+    #[test]
+    fn test_rank() {
+        let m = BitMatrix::from(Matrix::new([[1u8, 1, 0], [0, 1, 1], [1, 0, 1]]));
+        assert_eq!(m.rank(), 2);
+    }
+}
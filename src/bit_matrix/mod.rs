@@ -0,0 +1,6 @@
+pub mod arithmetic;
+pub mod core;
+pub mod display;
+pub mod linear_algebra;
+
+pub use core::BitMatrix;
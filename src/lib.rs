@@ -1,5 +1,10 @@
+pub mod bit_matrix;
 pub mod matrix;
+pub mod primitives;
+pub mod scalar;
+pub mod tape;
 pub mod vector;
 
-pub use matrix::Matrix;
+pub use bit_matrix::BitMatrix;
+pub use matrix::{Affine, Matrix};
 pub use vector::Vector;
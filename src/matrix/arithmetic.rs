@@ -102,13 +102,9 @@ where
 {
     type Output = Self;
     fn neg(self) -> Self::Output {
-        let mut result = self.data;
-        for i in 0..M {
-            for j in 0..N {
-                result[i][j] = -self.data[i][j];
-            }
+        Matrix {
+            data: self.data.map(|row| row.map(|x| -x)),
         }
-        Matrix { data: result }
     }
 }
 
@@ -0,0 +1,98 @@
+use super::core::Matrix;
+
+impl<T: Copy + Default, const M: usize, const N: usize> Matrix<T, M, N> {
+    pub fn hstack<const K: usize, const NK: usize>(
+        &self,
+        other: &Matrix<T, M, K>,
+    ) -> Matrix<T, M, NK> {
+        const { assert!(NK == N + K, "hstack column count must be N + K") }
+        let mut result = Matrix::<T, M, NK>::zeros();
+        for i in 0..M {
+            result[i][..N].copy_from_slice(&self.data[i]);
+            result[i][N..].copy_from_slice(&other.data[i]);
+        }
+        result
+    }
+
+    pub fn vstack<const K: usize, const MK: usize>(
+        &self,
+        other: &Matrix<T, K, N>,
+    ) -> Matrix<T, MK, N> {
+        const { assert!(MK == M + K, "vstack row count must be M + K") }
+        let mut result = Matrix::<T, MK, N>::zeros();
+        result.data[..M].copy_from_slice(&self.data);
+        result.data[M..].copy_from_slice(&other.data);
+        result
+    }
+
+    /// Assembles `[[a, b], [c, d]]` from four blocks whose sizes add up to `M` by `N`.
+    pub fn from_blocks<const M1: usize, const M2: usize, const N1: usize, const N2: usize>(
+        a: &Matrix<T, M1, N1>,
+        b: &Matrix<T, M1, N2>,
+        c: &Matrix<T, M2, N1>,
+        d: &Matrix<T, M2, N2>,
+    ) -> Self {
+        const {
+            assert!(M == M1 + M2, "block row counts must add up to M");
+            assert!(N == N1 + N2, "block column counts must add up to N");
+        }
+        let mut result = Self::zeros();
+        for i in 0..M1 {
+            result[i][..N1].copy_from_slice(&a.data[i]);
+            result[i][N1..].copy_from_slice(&b.data[i]);
+        }
+        for i in 0..M2 {
+            result[M1 + i][..N1].copy_from_slice(&c.data[i]);
+            result[M1 + i][N1..].copy_from_slice(&d.data[i]);
+        }
+        result
+    }
+
+    pub fn split_at_row<const R: usize, const R2: usize>(
+        &self,
+    ) -> (Matrix<T, R, N>, Matrix<T, R2, N>) {
+        const { assert!(R + R2 == M, "split rows must add up to M") }
+        (self.block::<0, 0, R, N>(), self.block::<R, 0, R2, N>())
+    }
+
+    pub fn split_at_col<const C: usize, const C2: usize>(
+        &self,
+    ) -> (Matrix<T, M, C>, Matrix<T, M, C2>) {
+        const { assert!(C + C2 == N, "split columns must add up to N") }
+        (self.block::<0, 0, M, C>(), self.block::<0, C, M, C2>())
+    }
+
+    /// Copies out the `R` by `C` block whose top-left corner is at row `R0`, column `C0`.
+    pub fn block<const R0: usize, const C0: usize, const R: usize, const C: usize>(
+        &self,
+    ) -> Matrix<T, R, C> {
+        const {
+            assert!(R0 + R <= M, "block rows out of bounds");
+            assert!(C0 + C <= N, "block columns out of bounds");
+        }
+        let mut result = Matrix::<T, R, C>::zeros();
+        for i in 0..R {
+            result[i].copy_from_slice(&self.data[R0 + i][C0..C0 + C]);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hstack() {
+        let a = Matrix::new([[1], [2]]);
+        let b = Matrix::new([[3, 4], [5, 6]]);
+        let m: Matrix<i32, 2, 3> = a.hstack(&b);
+        assert_eq!(m.data, [[1, 3, 4], [2, 5, 6]]);
+    }
+
+    #[test]
+    fn test_block() {
+        let m = Matrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(m.block::<1, 1, 2, 2>().data, [[5, 6], [8, 9]]);
+    }
+}
@@ -0,0 +1,193 @@
+use super::core::Matrix;
+use crate::scalar::{Angle, Real};
+use crate::vector::geometry::ZeroLengthError;
+use crate::vector::Vector;
+
+/// Right-handed builders look down `-z` with depth in `[-1, 1]`, as in OpenGL;
+/// the `_lh` variants look down `+z`.
+impl<T: Real> Matrix<T, 4, 4> {
+    pub fn frustum(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let (zero, one) = (T::default(), T::one());
+        let two = one + one;
+        Matrix::new([
+            [
+                two * near / (right - left),
+                zero,
+                (right + left) / (right - left),
+                zero,
+            ],
+            [
+                zero,
+                two * near / (top - bottom),
+                (top + bottom) / (top - bottom),
+                zero,
+            ],
+            [
+                zero,
+                zero,
+                (far + near) / (near - far),
+                two * far * near / (near - far),
+            ],
+            [zero, zero, -one, zero],
+        ])
+    }
+
+    pub fn frustum_lh(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        Self::frustum(left, right, bottom, top, near, far).flip_z()
+    }
+
+    /// `fovy` is the vertical field of view.
+    pub fn perspective(fovy: impl Into<Angle<T>>, aspect: T, near: T, far: T) -> Self {
+        let (top, right) = Self::near_extent(fovy, aspect, near);
+        Self::frustum(-right, right, -top, top, near, far)
+    }
+
+    pub fn perspective_lh(fovy: impl Into<Angle<T>>, aspect: T, near: T, far: T) -> Self {
+        Self::perspective(fovy, aspect, near, far).flip_z()
+    }
+
+    /// `perspective` with the far plane at infinity.
+    pub fn perspective_infinite(fovy: impl Into<Angle<T>>, aspect: T, near: T) -> Self {
+        let mut result = Self::perspective_reversed_z_infinite(fovy, aspect, near);
+        let two = T::one() + T::one();
+        result[2][2] = -T::one();
+        result[2][3] = -two * near;
+        result
+    }
+
+    /// Depth reversed into `[0, 1]`: near maps to 1 and far to 0.
+    pub fn perspective_reversed_z(fovy: impl Into<Angle<T>>, aspect: T, near: T, far: T) -> Self {
+        let mut result = Self::perspective_reversed_z_infinite(fovy, aspect, near);
+        result[2][2] = near / (far - near);
+        result[2][3] = far * near / (far - near);
+        result
+    }
+
+    /// Reversed-Z perspective with the far plane at infinity, mapped to 0.
+    pub fn perspective_reversed_z_infinite(fovy: impl Into<Angle<T>>, aspect: T, near: T) -> Self {
+        let (top, right) = Self::near_extent(fovy, aspect, near);
+        let (zero, one) = (T::default(), T::one());
+        Matrix::new([
+            [near / right, zero, zero, zero],
+            [zero, near / top, zero, zero],
+            [zero, zero, zero, near],
+            [zero, zero, -one, zero],
+        ])
+    }
+
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let (zero, one) = (T::default(), T::one());
+        let two = one + one;
+        Matrix::new([
+            [
+                two / (right - left),
+                zero,
+                zero,
+                -(right + left) / (right - left),
+            ],
+            [
+                zero,
+                two / (top - bottom),
+                zero,
+                -(top + bottom) / (top - bottom),
+            ],
+            [zero, zero, two / (near - far), (far + near) / (near - far)],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    pub fn orthographic_lh(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        Self::orthographic(left, right, bottom, top, near, far).flip_z()
+    }
+
+    /// Fails when `eye == target` or `up` is parallel to the line of sight.
+    pub fn look_at(
+        eye: &Vector<T, 3>,
+        target: &Vector<T, 3>,
+        up: &Vector<T, 3>,
+    ) -> Result<Self, ZeroLengthError> {
+        let forward = (*target - *eye).normalize()?;
+        let side = forward.cross(up).normalize()?;
+        let up = side.cross(&forward);
+        Ok(Self::view(&side, &up, &(forward * -T::one()), eye))
+    }
+
+    pub fn look_at_lh(
+        eye: &Vector<T, 3>,
+        target: &Vector<T, 3>,
+        up: &Vector<T, 3>,
+    ) -> Result<Self, ZeroLengthError> {
+        let forward = (*target - *eye).normalize()?;
+        let side = up.cross(&forward).normalize()?;
+        let up = forward.cross(&side);
+        Ok(Self::view(&side, &up, &forward, eye))
+    }
+
+    /// World space to normalized device coordinates; `None` on the camera plane.
+    pub fn project(&self, point: &Vector<T, 3>) -> Option<Vector<T, 3>> {
+        self.transform_point(point)
+    }
+
+    pub fn unproject(&self, ndc: &Vector<T, 3>) -> Option<Vector<T, 3>> {
+        self.inverse()?.project(ndc)
+    }
+
+    fn near_extent(fovy: impl Into<Angle<T>>, aspect: T, near: T) -> (T, T) {
+        let top = near * (fovy.into() / (T::one() + T::one())).tan();
+        (top, top * aspect)
+    }
+
+    /// Negates the third column.
+    fn flip_z(mut self) -> Self {
+        for i in 0..4 {
+            self[i][2] = -self[i][2];
+        }
+        self
+    }
+
+    fn view(
+        side: &Vector<T, 3>,
+        up: &Vector<T, 3>,
+        back: &Vector<T, 3>,
+        eye: &Vector<T, 3>,
+    ) -> Self {
+        let (zero, one) = (T::default(), T::one());
+        let row = |axis: &Vector<T, 3>| {
+            let [x, y, z] = axis.data;
+            [x, y, z, -axis.dot(eye)]
+        };
+        Matrix::new([row(side), row(up), row(back), [zero, zero, zero, one]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scalar::Deg;
+
+    #[test]
+    fn test_perspective_matches_frustum() {
+        let p: Matrix<f64, 4, 4> = Matrix::perspective(Deg(90.0), 2.0, 1.0, 3.0);
+        let f = Matrix::frustum(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((p[i][j] - f[i][j]).abs() < 1e-12);
+            }
+        }
+        assert_eq!(p[2], [0.0, 0.0, -2.0, -3.0]);
+    }
+
+    #[test]
+    fn test_look_at_translates_eye_to_origin() {
+        let eye = Vector::new([1.0, 2.0, 3.0]);
+        let view = Matrix::look_at(
+            &eye,
+            &Vector::new([1.0, 2.0, 0.0]),
+            &Vector::new([0.0, 1.0, 0.0]),
+        );
+        assert_eq!(
+            view.unwrap().transform_point(&eye),
+            Some(Vector::new([0.0, 0.0, 0.0]))
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::scalar::One;
+
 #[derive(Clone, Copy, PartialEq)]
 pub struct Matrix<T, const M: usize, const N: usize> {
     pub data: [[T; N]; M],
@@ -17,6 +19,16 @@ impl<T: Default + Copy, const M: usize, const N: usize> Matrix<T, M, N> {
     }
 }
 
+impl<T: Default + Copy + One, const M: usize> Matrix<T, M, M> {
+    pub fn identity() -> Self {
+        let mut result = Self::zeros();
+        for i in 0..M {
+            result.data[i][i] = T::one();
+        }
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,4 +44,10 @@ mod tests {
         let m: Matrix<i32, 2, 2> = Matrix::zeros();
         assert_eq!(m.data, [[0, 0], [0, 0]]);
     }
+
+    #[test]
+    fn test_identity() {
+        let m: Matrix<i32, 2, 2> = Matrix::identity();
+        assert_eq!(m.data, [[1, 0], [0, 1]]);
+    }
 }
@@ -0,0 +1,108 @@
+use super::core::Matrix;
+use crate::scalar::One;
+use core::ops::{Add, Mul};
+
+impl<T: Copy, const M: usize, const N: usize> Matrix<T, M, N> {
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < M && b < M, "Matrix row index out of bounds");
+        self.data.swap(a, b);
+    }
+
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        assert!(a < N && b < N, "Matrix column index out of bounds");
+        for row in self.data.iter_mut() {
+            row.swap(a, b);
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy, const M: usize, const N: usize> Matrix<T, M, N> {
+    pub fn scale_row(&mut self, row: usize, factor: T) {
+        assert!(row < M, "Matrix row index out of bounds");
+        for k in 0..N {
+            self.data[row][k] = self.data[row][k] * factor;
+        }
+    }
+
+    pub fn scale_col(&mut self, col: usize, factor: T) {
+        assert!(col < N, "Matrix column index out of bounds");
+        for k in 0..M {
+            self.data[k][col] = self.data[k][col] * factor;
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Add<Output = T> + Mul<Output = T> + Copy,
+{
+    /// Adds `factor` times row `source` to row `target`.
+    pub fn add_scaled_row(&mut self, target: usize, source: usize, factor: T) {
+        assert!(target < M && source < M, "Matrix row index out of bounds");
+        let source_row = self.data[source];
+        for (x, &s) in self.data[target].iter_mut().zip(source_row.iter()) {
+            *x = *x + factor * s;
+        }
+    }
+
+    /// Adds `factor` times column `source` to column `target`.
+    pub fn add_scaled_col(&mut self, target: usize, source: usize, factor: T) {
+        assert!(
+            target < N && source < N,
+            "Matrix column index out of bounds"
+        );
+        for row in self.data.iter_mut() {
+            let source_value = row[source];
+            row[target] = row[target] + factor * source_value;
+        }
+    }
+}
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Add<Output = T> + Mul<Output = T> + Default + Copy + One,
+{
+    /// The identity with rows `a` and `b` swapped.
+    pub fn elementary_swap(a: usize, b: usize) -> Self {
+        let mut result = Self::identity();
+        result.swap_rows(a, b);
+        result
+    }
+
+    /// The identity with row `row` scaled by `factor`.
+    pub fn elementary_scale(row: usize, factor: T) -> Self {
+        let mut result = Self::identity();
+        result.scale_row(row, factor);
+        result
+    }
+
+    /// The identity with `factor` times row `source` added to row `target`.
+    pub fn elementary_add(target: usize, source: usize, factor: T) -> Self {
+        let mut result = Self::identity();
+        result.add_scaled_row(target, source, factor);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_operations() {
+        let mut m = Matrix::new([[1, 2], [3, 4]]);
+        m.swap_rows(0, 1);
+        assert_eq!(m.data, [[3, 4], [1, 2]]);
+        m.scale_row(1, 2);
+        assert_eq!(m.data, [[3, 4], [2, 4]]);
+        m.add_scaled_row(0, 1, -1);
+        assert_eq!(m.data, [[1, 0], [2, 4]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_swap_rows_out_of_bounds() {
+        let mut m = Matrix::new([[1, 2], [3, 4]]);
+        m.swap_rows(0, 2);
+    }
+}
@@ -0,0 +1,267 @@
+use super::core::Matrix;
+use super::linear_algebra::gauss_jordan;
+use crate::scalar::Field;
+use core::fmt::Display;
+
+/// A single step of Gauss-Jordan elimination. Row indices are zero-based.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operation<T> {
+    Pivot {
+        row: usize,
+        col: usize,
+    },
+    SwapRows {
+        a: usize,
+        b: usize,
+    },
+    ScaleRow {
+        row: usize,
+        factor: T,
+    },
+    AddScaledRow {
+        target: usize,
+        source: usize,
+        factor: T,
+    },
+}
+
+impl<T: Field> Operation<T> {
+    /// Applies the operation to `[left | right]`. Entries of the pivot column
+    /// `col` that the operation eliminates are set exactly.
+    pub(crate) fn apply<const M: usize, const N: usize, const K: usize>(
+        &self,
+        left: &mut Matrix<T, M, N>,
+        right: &mut Matrix<T, M, K>,
+        col: usize,
+    ) {
+        match *self {
+            Operation::Pivot { .. } => {}
+            Operation::SwapRows { a, b } => {
+                left.swap_rows(a, b);
+                right.swap_rows(a, b);
+            }
+            Operation::ScaleRow { row, factor } => {
+                left.scale_row(row, factor);
+                right.scale_row(row, factor);
+                left[row][col] = T::one();
+            }
+            Operation::AddScaledRow {
+                target,
+                source,
+                factor,
+            } => {
+                left.add_scaled_row(target, source, factor);
+                right.add_scaled_row(target, source, factor);
+                left[target][col] = T::default();
+            }
+        }
+    }
+}
+
+/// The recorded elimination of `[left | right]`; `K` is zero for `rref`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Explanation<T, const M: usize, const N: usize, const K: usize> {
+    pub left: Matrix<T, M, N>,
+    pub right: Matrix<T, M, K>,
+    pub operations: Vec<Operation<T>>,
+}
+
+impl<T: Field, const M: usize, const N: usize> Matrix<T, M, N> {
+    pub fn rref_explained(&self) -> Explanation<T, M, N, 0> {
+        Explanation::record(*self, Matrix::zeros())
+    }
+}
+
+impl<T: Field, const M: usize> Matrix<T, M, M> {
+    pub fn inverse_explained(&self) -> (Option<Self>, Explanation<T, M, M, M>) {
+        let explanation = Explanation::record(*self, Self::identity());
+        let (left, right) = explanation.result();
+        let inverse = if left == Self::identity() {
+            Some(right)
+        } else {
+            None
+        };
+        (inverse, explanation)
+    }
+}
+
+impl<T: Field, const M: usize, const N: usize, const K: usize> Explanation<T, M, N, K> {
+    fn record(left: Matrix<T, M, N>, right: Matrix<T, M, K>) -> Self {
+        let mut operations = Vec::new();
+        let (mut l, mut r) = (left, right);
+        gauss_jordan(&mut l, &mut r, |operation| operations.push(operation));
+        Self {
+            left,
+            right,
+            operations,
+        }
+    }
+
+    /// Passes each operation with the state right after it.
+    fn replay(&self, mut visit: impl FnMut(&Operation<T>, &Matrix<T, M, N>, &Matrix<T, M, K>)) {
+        let (mut left, mut right) = (self.left, self.right);
+        let mut col = 0;
+        for operation in &self.operations {
+            if let Operation::Pivot { col: pivot_col, .. } = *operation {
+                col = pivot_col;
+            }
+            operation.apply(&mut left, &mut right, col);
+            visit(operation, &left, &right);
+        }
+    }
+
+    /// The final state of `[left | right]`.
+    pub fn result(&self) -> (Matrix<T, M, N>, Matrix<T, M, K>) {
+        let mut result = (self.left, self.right);
+        self.replay(|_, left, right| result = (*left, *right));
+        result
+    }
+}
+
+impl<T: Field + Display, const M: usize, const N: usize, const K: usize> Explanation<T, M, N, K> {
+    pub fn to_text(&self) -> String {
+        let mut out = String::from("Start:\n");
+        out += &text_matrix(&self.left, &self.right);
+        self.replay(|operation, left, right| {
+            out += "\n";
+            out += &match operation {
+                Operation::Pivot { row, col } => {
+                    format!("Pivot at row {}, column {}\n", row + 1, col + 1)
+                }
+                Operation::SwapRows { a, b } => format!("R{} <-> R{}\n", a + 1, b + 1),
+                Operation::ScaleRow { row, factor } => {
+                    format!("R{} <- ({}) R{}\n", row + 1, factor, row + 1)
+                }
+                Operation::AddScaledRow {
+                    target,
+                    source,
+                    factor,
+                } => format!(
+                    "R{} <- R{} + ({}) R{}\n",
+                    target + 1,
+                    target + 1,
+                    factor,
+                    source + 1
+                ),
+            };
+            if !matches!(operation, Operation::Pivot { .. }) {
+                out += &text_matrix(left, right);
+            }
+        });
+        out
+    }
+
+    pub fn to_latex(&self) -> String {
+        let mut out = latex_matrix(&self.left, &self.right);
+        self.replay(|operation, left, right| {
+            let label = match operation {
+                Operation::Pivot { .. } => return,
+                Operation::SwapRows { a, b } => {
+                    format!("R_{{{}}} \\leftrightarrow R_{{{}}}", a + 1, b + 1)
+                }
+                Operation::ScaleRow { row, factor } => {
+                    format!("R_{{{}}} \\to ({}) R_{{{}}}", row + 1, factor, row + 1)
+                }
+                Operation::AddScaledRow {
+                    target,
+                    source,
+                    factor,
+                } => format!(
+                    "R_{{{}}} \\to R_{{{}}} + ({}) R_{{{}}}",
+                    target + 1,
+                    target + 1,
+                    factor,
+                    source + 1
+                ),
+            };
+            out += &format!(
+                "\n\\xrightarrow{{{}}}\n{}",
+                label,
+                latex_matrix(left, right)
+            );
+        });
+        out
+    }
+}
+
+fn text_matrix<T: Display, const M: usize, const N: usize, const K: usize>(
+    left: &Matrix<T, M, N>,
+    right: &Matrix<T, M, K>,
+) -> String {
+    let cells: Vec<Vec<String>> = (0..M)
+        .map(|i| {
+            left.data[i]
+                .iter()
+                .chain(right.data[i].iter())
+                .map(|value| value.to_string())
+                .collect()
+        })
+        .collect();
+    let width = cells.iter().flatten().map(|c| c.len()).max().unwrap_or(0);
+    let mut out = String::new();
+    for row in &cells {
+        out += "[";
+        for (j, cell) in row.iter().enumerate() {
+            if j == N && K > 0 {
+                out += " |";
+            }
+            out += &format!(" {:>width$}", cell, width = width);
+        }
+        out += " ]\n";
+    }
+    out
+}
+
+fn latex_matrix<T: Display, const M: usize, const N: usize, const K: usize>(
+    left: &Matrix<T, M, N>,
+    right: &Matrix<T, M, K>,
+) -> String {
+    let mut spec = "c".repeat(N);
+    if K > 0 {
+        spec += "|";
+        spec += &"c".repeat(K);
+    }
+    let rows: Vec<String> = (0..M)
+        .map(|i| {
+            left.data[i]
+                .iter()
+                .chain(right.data[i].iter())
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(" & ")
+        })
+        .collect();
+    format!(
+        "\\left[\\begin{{array}}{{{}}}\n{}\n\\end{{array}}\\right]",
+        spec,
+        rows.join(" \\\\\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rref_explained_steps() {
+        let m: Matrix<f64, 2, 2> = Matrix::new([[0.0, 2.0], [1.0, 1.0]]);
+        let explanation = m.rref_explained();
+        assert_eq!(
+            explanation.operations[1],
+            Operation::SwapRows { a: 0, b: 1 }
+        );
+        assert_eq!(explanation.result().0, m.rref());
+    }
+
+    #[test]
+    fn test_to_latex() {
+        let m: Matrix<f64, 1, 1> = Matrix::new([[2.0]]);
+        let (_, explanation) = m.inverse_explained();
+        assert_eq!(
+            explanation.to_latex(),
+            "\\left[\\begin{array}{c|c}\n2 & 1\n\\end{array}\\right]\n\
+             \\xrightarrow{R_{1} \\to (0.5) R_{1}}\n\
+             \\left[\\begin{array}{c|c}\n1 & 0.5\n\\end{array}\\right]"
+        );
+    }
+}
@@ -1,98 +1,221 @@
 use super::core::Matrix;
+use super::explain::Operation;
+use crate::scalar::{Conjugate, Field, Integer};
+use crate::vector::Vector;
+use core::array;
+use core::fmt;
+use core::ops::Add;
 
 impl<T: Copy + Default, const M: usize, const N: usize> Matrix<T, M, N> {
     pub fn transpose(&self) -> Matrix<T, N, M> {
-        let mut transposed_data = [[T::default(); M]; N];
+        Matrix {
+            data: array::from_fn(|j| array::from_fn(|i| self.data[i][j])),
+        }
+    }
+}
+
+impl<T: Copy + Default + Conjugate, const M: usize, const N: usize> Matrix<T, M, N> {
+    pub fn conjugate(&self) -> Self {
+        let mut result = *self;
         for i in 0..M {
             for j in 0..N {
-                transposed_data[j][i] = self.data[i][j];
+                result.data[i][j] = self.data[i][j].conjugate();
             }
         }
-        Matrix {
-            data: transposed_data,
-        }
+        result
+    }
+
+    /// The conjugate transpose.
+    pub fn adjoint(&self) -> Matrix<T, N, M> {
+        self.conjugate().transpose()
     }
 }
 
-impl<const M: usize> Matrix<f64, M, M> {
-    pub fn determinant(&self) -> f64 {
+impl<T: Add<Output = T> + Copy + Default, const M: usize> Matrix<T, M, M> {
+    pub fn trace(&self) -> T {
+        (0..M).fold(T::default(), |acc, i| acc + self.data[i][i])
+    }
+}
+
+impl<T: Field, const M: usize> Matrix<T, M, M> {
+    pub fn determinant(&self) -> T {
         let mut mat = *self;
-        let mut det = 1.0;
-        let mut sign = 1.0;
+        let mut det = T::one();
+        let mut negate = false;
 
         for i in 0..M {
             let mut pivot = i;
             for j in i..M {
-                if mat[j][i].abs() > 1e-9 {
+                if !mat[j][i].is_negligible() {
                     pivot = j;
                     break;
                 }
             }
-            if mat[pivot][i].abs() <= 1e-9 {
-                return 0.0;
+            if mat[pivot][i].is_negligible() {
+                return T::unresolved_determinant();
             }
             if pivot != i {
-                mat.data.swap(i, pivot);
-                sign = -sign;
+                mat.swap_rows(i, pivot);
+                negate = !negate;
             }
             let pivot_val = mat[i][i];
             for j in (i + 1)..M {
                 let factor = mat[j][i] / pivot_val;
-                for k in i..M {
-                    let val_ik = mat[i][k];
-                    mat[j][k] -= factor * val_ik;
-                }
+                mat.add_scaled_row(j, i, -factor);
             }
-            det *= pivot_val;
+            det = det * pivot_val;
         }
-        det * sign
-    }
-
-    pub fn identity() -> Self {
-        let mut result = Self::zeros();
-        for i in 0..M {
-            result[i][i] = 1.0;
+        if negate {
+            -det
+        } else {
+            det
         }
-        result
     }
 
     pub fn inverse(&self) -> Option<Self> {
         let mut mat = *self;
         let mut inv = Self::identity();
-        for i in 0..M {
-            let mut pivot = i;
-            for j in i..M {
-                if mat[j][i].abs() > 1e-9 {
-                    pivot = j;
-                    break;
+        if gauss_jordan(&mut mat, &mut inv, |_| {}) < M {
+            return None;
+        }
+        Some(inv)
+    }
+
+    pub fn solve(&self, b: &Vector<T, M>) -> Option<Vector<T, M>> {
+        let mut mat = *self;
+        let mut rhs = Matrix {
+            data: array::from_fn(|i| [b.data[i]]),
+        };
+        if gauss_jordan(&mut mat, &mut rhs, |_| {}) < M {
+            return None;
+        }
+        Some(Vector {
+            data: array::from_fn(|i| rhs.data[i][0]),
+        })
+    }
+}
+
+/// Returned when an exact computation does not fit in the scalar type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OverflowError;
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("arithmetic overflow in exact computation")
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+/// Returned when an operation needs the inverse of a singular matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SingularMatrixError;
+
+impl fmt::Display for SingularMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("matrix is singular")
+    }
+}
+
+impl std::error::Error for SingularMatrixError {}
+
+impl<T: Integer, const M: usize> Matrix<T, M, M> {
+    /// Bareiss elimination, whose divisions are all exact.
+    pub fn determinant_exact(&self) -> Result<T, OverflowError> {
+        if M == 0 {
+            return Ok(T::one());
+        }
+        let mut mat = *self;
+        let mut prev = T::one();
+        let mut negate = false;
+        for k in 0..M - 1 {
+            if mat[k][k] == T::default() {
+                match ((k + 1)..M).find(|&j| mat[j][k] != T::default()) {
+                    Some(pivot) => {
+                        mat.swap_rows(k, pivot);
+                        negate = !negate;
+                    }
+                    None => return Ok(T::default()),
                 }
             }
-            if mat[pivot][i].abs() <= 1e-9 {
-                return None;
-            }
-            if pivot != i {
-                mat.data.swap(i, pivot);
-                inv.data.swap(i, pivot);
-            }
-            let pivot_val = mat[i][i];
-            let factor = 1.0 / pivot_val;
-            for k in 0..M {
-                mat[i][k] *= factor;
-                inv[i][k] *= factor;
-            }
-            for j in 0..M {
-                if j != i {
-                    let factor = mat[j][i];
-                    for k in 0..M {
-                        let val_ik_mat = mat[i][k];
-                        let val_ik_inv = inv[i][k];
-                        mat[j][k] -= factor * val_ik_mat;
-                        inv[j][k] -= factor * val_ik_inv;
-                    }
+            for i in (k + 1)..M {
+                for j in (k + 1)..M {
+                    let lhs = mat[i][j].checked_mul(mat[k][k]).ok_or(OverflowError)?;
+                    let rhs = mat[i][k].checked_mul(mat[k][j]).ok_or(OverflowError)?;
+                    mat[i][j] = lhs
+                        .checked_sub(rhs)
+                        .and_then(|d| d.checked_div(prev))
+                        .ok_or(OverflowError)?;
                 }
             }
+            prev = mat[k][k];
+        }
+        let det = mat[M - 1][M - 1];
+        if negate {
+            det.checked_neg().ok_or(OverflowError)
+        } else {
+            Ok(det)
         }
-        Some(inv)
+    }
+}
+
+/// Gauss-Jordan elimination of `left`, applying every row operation to `right`
+/// as well and reporting it to `on_step`. Returns the rank of `left`.
+pub(crate) fn gauss_jordan<T: Field, const M: usize, const N: usize, const K: usize>(
+    left: &mut Matrix<T, M, N>,
+    right: &mut Matrix<T, M, K>,
+    mut on_step: impl FnMut(Operation<T>),
+) -> usize {
+    let mut row = 0;
+    for col in 0..N {
+        if row == M {
+            break;
+        }
+        let pivot = match (row..M).find(|&j| !left[j][col].is_negligible()) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        on_step(Operation::Pivot { row: pivot, col });
+        let mut step = |operation: Operation<T>, left: &mut Matrix<T, M, N>| {
+            operation.apply(left, right, col);
+            on_step(operation);
+        };
+        if pivot != row {
+            step(Operation::SwapRows { a: row, b: pivot }, left);
+        }
+        let factor = T::one() / left[row][col];
+        if factor != T::one() {
+            step(Operation::ScaleRow { row, factor }, left);
+        }
+        for j in 0..M {
+            if j != row && left[j][col] != T::default() {
+                let factor = -left[j][col];
+                step(
+                    Operation::AddScaledRow {
+                        target: j,
+                        source: row,
+                        factor,
+                    },
+                    left,
+                );
+            }
+        }
+        row += 1;
+    }
+    row
+}
+
+impl<T: Field, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Reduced row echelon form.
+    pub fn rref(&self) -> Self {
+        let mut result = *self;
+        gauss_jordan(&mut result, &mut Matrix::<T, M, 0>::zeros(), |_| {});
+        result
+    }
+
+    pub fn rank(&self) -> usize {
+        let mut mat = *self;
+        gauss_jordan(&mut mat, &mut Matrix::<T, M, 0>::zeros(), |_| {})
     }
 }
 
@@ -109,7 +232,26 @@ mod tests {
 
     #[test]
     fn test_determinant() {
-        let m = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+        let m: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
         assert!((m.determinant() - -2.0).abs() < 1e-9);
     }
+
+    #[test]
+    fn test_trace() {
+        let m = Matrix::new([[1, 2], [3, 4]]);
+        assert_eq!(m.trace(), 5);
+    }
+
+    #[test]
+    fn test_rref() {
+        let m: Matrix<f64, 2, 3> = Matrix::new([[2.0, 4.0, 6.0], [1.0, 2.0, 4.0]]);
+        assert_eq!(m.rref().data, [[1.0, 2.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert_eq!(m.rank(), 2);
+    }
+
+    #[test]
+    fn test_determinant_exact() {
+        let m = Matrix::new([[1i64, 2], [3, 4]]);
+        assert_eq!(m.determinant_exact(), Ok(-2));
+    }
 }
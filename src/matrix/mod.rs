@@ -1,7 +1,17 @@
 pub mod arithmetic;
+pub mod blocks;
+pub mod camera;
 pub mod core;
 pub mod display;
+pub mod elementary;
+pub mod explain;
 pub mod indexing;
 pub mod linear_algebra;
+pub mod power;
+pub mod products;
+pub mod rotation;
+pub mod transform;
 
 pub use core::Matrix;
+pub use rotation::{EulerFrame, EulerOrder};
+pub use transform::Affine;
@@ -0,0 +1,56 @@
+use super::core::Matrix;
+use super::linear_algebra::SingularMatrixError;
+use crate::scalar::{Field, One};
+use core::ops::{Add, Mul};
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: One + Mul<Output = T> + Add<Output = T> + Default + Copy + core::iter::Sum,
+{
+    pub fn pow(&self, exp: u32) -> Self {
+        let mut result = Self::identity();
+        let mut base = *self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+        result
+    }
+}
+
+impl<T: Field, const M: usize> Matrix<T, M, M> {
+    /// Negative exponents raise the inverse.
+    pub fn powi(&self, exp: i32) -> Result<Self, SingularMatrixError> {
+        if exp < 0 {
+            let inverse = self.inverse().ok_or(SingularMatrixError)?;
+            Ok(inverse.pow(exp.unsigned_abs()))
+        } else {
+            Ok(self.pow(exp as u32))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pow() {
+        let fib = Matrix::new([[1u64, 1], [1, 0]]);
+        assert_eq!(fib.pow(10).data, [[89, 55], [55, 34]]);
+        assert_eq!(fib.pow(0), Matrix::identity());
+    }
+
+    #[test]
+    fn test_powi_singular() {
+        let m = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(m.powi(-1), Err(SingularMatrixError));
+        assert!(m.powi(2).is_ok());
+    }
+}
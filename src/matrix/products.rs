@@ -0,0 +1,102 @@
+use super::core::Matrix;
+use crate::scalar::Semiring;
+use core::ops::{Div, Mul};
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Mul<Output = T> + Copy + Default,
+{
+    /// `MP` and `NQ` are checked against `M * P` and `N * Q` at compile time.
+    pub fn kronecker<const P: usize, const Q: usize, const MP: usize, const NQ: usize>(
+        &self,
+        other: &Matrix<T, P, Q>,
+    ) -> Matrix<T, MP, NQ> {
+        const {
+            assert!(MP == M * P, "Kronecker product row count must be M * P");
+            assert!(NQ == N * Q, "Kronecker product column count must be N * Q");
+        }
+        let mut result = Matrix::<T, MP, NQ>::zeros();
+        for i in 0..M {
+            for j in 0..N {
+                for k in 0..P {
+                    for l in 0..Q {
+                        result[i * P + k][j * Q + l] = self[i][j] * other[k][l];
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    pub fn hadamard(&self, other: &Self) -> Self {
+        let mut result = Self::zeros();
+        for i in 0..M {
+            for j in 0..N {
+                result[i][j] = self[i][j] * other[i][j];
+            }
+        }
+        result
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Div<Output = T> + Copy + Default,
+{
+    pub fn hadamard_div(&self, other: &Self) -> Self {
+        let mut result = Self::zeros();
+        for i in 0..M {
+            for j in 0..N {
+                result[i][j] = self[i][j] / other[i][j];
+            }
+        }
+        result
+    }
+}
+
+impl<S: Semiring, const M: usize, const K: usize> Matrix<S, M, K> {
+    pub fn semiring_mul<const N: usize>(&self, rhs: &Matrix<S, K, N>) -> Matrix<S, M, N> {
+        let mut result = Matrix {
+            data: [[S::zero(); N]; M],
+        };
+        for i in 0..M {
+            for j in 0..N {
+                result[i][j] =
+                    (0..K).fold(S::zero(), |acc, k| acc.plus(self[i][k].times(rhs[k][j])));
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scalar::BoolOr;
+
+    #[test]
+    fn test_kronecker() {
+        let a = Matrix::new([[1, 2]]);
+        let b = Matrix::new([[1], [10]]);
+        let k: Matrix<i32, 2, 2> = a.kronecker(&b);
+        assert_eq!(k.data, [[1, 2], [10, 20]]);
+    }
+
+    #[test]
+    fn test_hadamard() {
+        let a = Matrix::new([[1, 2], [3, 4]]);
+        assert_eq!(a.hadamard(&a).data, [[1, 4], [9, 16]]);
+    }
+
+    #[test]
+    fn test_semiring_mul() {
+        let a = Matrix::new([[true, false], [true, true]].map(|row| row.map(BoolOr)));
+        let b = Matrix::new([[false, true], [true, false]].map(|row| row.map(BoolOr)));
+        assert_eq!(
+            a.semiring_mul(&b).data,
+            [[false, true], [true, true]].map(|row| row.map(BoolOr))
+        );
+        let c = Matrix::new([[1, 2], [3, 4]]);
+        assert_eq!(c.semiring_mul(&c), c * c);
+    }
+}
@@ -0,0 +1,206 @@
+use super::core::Matrix;
+use crate::scalar::{Angle, Quaternion, Real};
+use crate::vector::geometry::ZeroLengthError;
+use crate::vector::Vector;
+
+/// The axis sequence of an Euler angle triple.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+/// Rotating about the body axes (intrinsic) or the fixed world axes (extrinsic).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EulerFrame {
+    Intrinsic,
+    Extrinsic,
+}
+
+impl EulerOrder {
+    fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+}
+
+impl<T: Real> Matrix<T, 3, 3> {
+    /// The rotation by `angles[n]` about the `n`th axis of `order`.
+    pub fn from_euler(angles: [Angle<T>; 3], order: EulerOrder, frame: EulerFrame) -> Self {
+        let [i, j, k] = order.axes();
+        let [a, b, c] = angles;
+        let (first, second, third) = (
+            axis_rotation(i, a),
+            axis_rotation(j, b),
+            axis_rotation(k, c),
+        );
+        match frame {
+            EulerFrame::Intrinsic => first * second * third,
+            EulerFrame::Extrinsic => third * second * first,
+        }
+    }
+
+    /// The middle angle is in `[-π/2, π/2]`, or `[0, π]` for proper Euler orders.
+    /// At gimbal lock the last angle applied to the body is zero.
+    pub fn to_euler(&self, order: EulerOrder, frame: EulerFrame) -> [Angle<T>; 3] {
+        let [i, j, k] = order.axes();
+        match frame {
+            EulerFrame::Intrinsic => self.intrinsic_euler_angles(i, j, k),
+            EulerFrame::Extrinsic => {
+                let [c, b, a] = self.intrinsic_euler_angles(k, j, i);
+                [a, b, c]
+            }
+        }
+    }
+
+    fn intrinsic_euler_angles(&self, i: usize, j: usize, k: usize) -> [Angle<T>; 3] {
+        let r = &self.data;
+        let s = if j == (i + 1) % 3 {
+            T::one()
+        } else {
+            -T::one()
+        };
+        let proper = i == k;
+        let k = 3 - i - j;
+        let (b, locked) = if proper {
+            let sin_b = (r[i][j] * r[i][j] + r[i][k] * r[i][k]).sqrt();
+            (Angle::atan2(sin_b, r[i][i]), sin_b.is_negligible())
+        } else {
+            let cos_b = (r[i][i] * r[i][i] + r[i][j] * r[i][j]).sqrt();
+            (Angle::atan2(s * r[i][k], cos_b), cos_b.is_negligible())
+        };
+        if locked {
+            return [Angle::atan2(s * r[k][j], r[j][j]), b, Angle::default()];
+        }
+        if proper {
+            [
+                Angle::atan2(r[j][i], -s * r[k][i]),
+                b,
+                Angle::atan2(r[i][j], s * r[i][k]),
+            ]
+        } else {
+            [
+                Angle::atan2(-s * r[j][k], r[k][k]),
+                b,
+                Angle::atan2(-s * r[i][j], r[i][i]),
+            ]
+        }
+    }
+
+    pub fn from_axis_angle(
+        axis: &Vector<T, 3>,
+        angle: impl Into<Angle<T>>,
+    ) -> Result<Self, ZeroLengthError> {
+        let n = axis.normalize()?;
+        let (sin, cos) = angle.into().sin_cos();
+        Ok(Self::identity() * cos + n.cross_matrix() * sin + n.outer(&n) * (T::one() - cos))
+    }
+
+    /// Angle in `[0, π]`.
+    pub fn to_axis_angle(&self) -> (Vector<T, 3>, Angle<T>) {
+        Quaternion::from_rotation_matrix(self).to_axis_angle()
+    }
+
+    /// Rotates by `|v|` radians about `v`.
+    pub fn from_rotation_vector(v: &Vector<T, 3>) -> Self {
+        Self::from_axis_angle(v, Angle::from_radians(v.magnitude()))
+            .unwrap_or_else(|_| Self::identity())
+    }
+
+    pub fn to_rotation_vector(&self) -> Vector<T, 3> {
+        let (axis, angle) = self.to_axis_angle();
+        axis * angle.radians()
+    }
+}
+
+impl<T: Real, const M: usize> Matrix<T, M, M> {
+    /// Whether `AᵀA - I` and `det A - 1` are within `tolerance` of zero.
+    pub fn is_rotation(&self, tolerance: T) -> bool {
+        let gram = self.transpose() * *self - Self::identity();
+        gram.data
+            .iter()
+            .flatten()
+            .all(|entry| entry.abs() <= tolerance)
+            && (self.determinant() - T::one()).abs() <= tolerance
+    }
+
+    /// The nearest rotation in the Frobenius norm; `None` if singular or reflecting.
+    pub fn reorthonormalize(&self) -> Option<Self> {
+        if self.determinant() <= T::default() {
+            return None;
+        }
+        let half = T::one() / (T::one() + T::one());
+        let mut x = *self;
+        for _ in 0..32 {
+            let next = (x + x.inverse()?.transpose()) * half;
+            let converged = (next - x).data.iter().flatten().all(|d| d.is_negligible());
+            x = next;
+            if converged {
+                break;
+            }
+        }
+        Some(x)
+    }
+}
+
+fn axis_rotation<T: Real>(axis: usize, angle: Angle<T>) -> Matrix<T, 3, 3> {
+    let (sin, cos) = angle.sin_cos();
+    let mut result = Matrix::identity();
+    let (p, q) = ((axis + 1) % 3, (axis + 2) % 3);
+    result[p][p] = cos;
+    result[p][q] = -sin;
+    result[q][p] = sin;
+    result[q][q] = cos;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis_rotation_matches_rotation_builders() {
+        let m = Matrix::<f64, 4, 4>::rotation_y(Angle::from_radians(0.3));
+        let r = axis_rotation(1, Angle::from_radians(0.3));
+        for i in 0..3 {
+            assert_eq!(r[i][..], m[i][..3]);
+        }
+    }
+
+    #[test]
+    fn test_yaw_pitch_roll() {
+        let r: Matrix<f64, 3, 3> = Matrix::from_euler(
+            [0.1, 0.2, 0.3].map(Angle::from_radians),
+            EulerOrder::ZYX,
+            EulerFrame::Intrinsic,
+        );
+        let [roll, pitch, yaw] = r
+            .to_euler(EulerOrder::XYZ, EulerFrame::Extrinsic)
+            .map(Angle::radians);
+        assert!(
+            (roll - 0.3).abs() < 1e-12 && (pitch - 0.2).abs() < 1e-12 && (yaw - 0.1).abs() < 1e-12
+        );
+    }
+}
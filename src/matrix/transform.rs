@@ -0,0 +1,240 @@
+use super::core::Matrix;
+use crate::scalar::{Angle, Field, Real, Ring};
+use crate::vector::geometry::ZeroLengthError;
+use crate::vector::Vector;
+use core::array;
+use core::ops::Mul;
+
+/// Homogeneous transforms: an `M` by `M` matrix acts on `M - 1` coordinates.
+impl<T: Ring, const M: usize> Matrix<T, M, M> {
+    pub fn translation<const N: usize>(offset: &Vector<T, N>) -> Self {
+        const { assert!(M == N + 1, "homogeneous matrix size must be N + 1") }
+        let mut result = Self::identity();
+        for i in 0..N {
+            result[i][N] = offset.data[i];
+        }
+        result
+    }
+
+    pub fn scaling<const N: usize>(factors: &Vector<T, N>) -> Self {
+        const { assert!(M == N + 1, "homogeneous matrix size must be N + 1") }
+        let mut result = Self::identity();
+        for i in 0..N {
+            result[i][i] = factors.data[i];
+        }
+        result
+    }
+
+    /// Adds `factor` times coordinate `source` to coordinate `target`.
+    pub fn shear(target: usize, source: usize, factor: T) -> Self {
+        assert!(
+            target < M - 1 && source < M - 1,
+            "shear axis out of bounds: {} or {} >= {}",
+            target,
+            source,
+            M - 1
+        );
+        assert!(target != source, "shear axes must differ");
+        let mut result = Self::identity();
+        result[target][source] = factor;
+        result
+    }
+
+    /// Divides by the resulting `w`; `None` if it is negligible.
+    pub fn transform_point<const N: usize>(&self, point: &Vector<T, N>) -> Option<Vector<T, N>>
+    where
+        T: Field,
+    {
+        const { assert!(M == N + 1, "homogeneous matrix size must be N + 1") }
+        let row = |i: usize| (0..N).map(|j| self[i][j] * point.data[j]).sum::<T>() + self[i][N];
+        let w = row(N);
+        if w == T::one() {
+            return Some(Vector::new(array::from_fn(row)));
+        }
+        if w.is_negligible() {
+            return None;
+        }
+        Some(Vector::new(array::from_fn(|i| row(i) / w)))
+    }
+
+    /// Ignores translation.
+    pub fn transform_vector<const N: usize>(&self, vector: &Vector<T, N>) -> Vector<T, N> {
+        const { assert!(M == N + 1, "homogeneous matrix size must be N + 1") }
+        Vector::new(array::from_fn(|i| {
+            (0..N).map(|j| self[i][j] * vector.data[j]).sum()
+        }))
+    }
+}
+
+impl<T: Field, const M: usize> Matrix<T, M, M> {
+    /// Across the hyperplane through the origin with the given normal.
+    pub fn reflection<const N: usize>(normal: &Vector<T, N>) -> Result<Self, ZeroLengthError> {
+        const { assert!(M == N + 1, "homogeneous matrix size must be N + 1") }
+        let denom = (0..N).map(|i| normal.data[i] * normal.data[i]).sum::<T>();
+        if denom.is_negligible() {
+            return Err(ZeroLengthError);
+        }
+        let two = T::one() + T::one();
+        let mut result = Self::identity();
+        for i in 0..N {
+            for j in 0..N {
+                result[i][j] = result[i][j] - two * normal.data[i] * normal.data[j] / denom;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<T: Real> Matrix<T, 3, 3> {
+    /// Counterclockwise.
+    pub fn rotation_2d(angle: impl Into<Angle<T>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        let (zero, one) = (T::default(), T::one());
+        Matrix::new([[cos, -sin, zero], [sin, cos, zero], [zero, zero, one]])
+    }
+}
+
+impl<T: Real> Matrix<T, 4, 4> {
+    pub fn rotation_x(angle: impl Into<Angle<T>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        let (zero, one) = (T::default(), T::one());
+        Matrix::new([
+            [one, zero, zero, zero],
+            [zero, cos, -sin, zero],
+            [zero, sin, cos, zero],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    pub fn rotation_y(angle: impl Into<Angle<T>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        let (zero, one) = (T::default(), T::one());
+        Matrix::new([
+            [cos, zero, sin, zero],
+            [zero, one, zero, zero],
+            [-sin, zero, cos, zero],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    pub fn rotation_z(angle: impl Into<Angle<T>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        let (zero, one) = (T::default(), T::one());
+        Matrix::new([
+            [cos, -sin, zero, zero],
+            [sin, cos, zero, zero],
+            [zero, zero, one, zero],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    pub fn rotation_axis(
+        axis: &Vector<T, 3>,
+        angle: impl Into<Angle<T>>,
+    ) -> Result<Self, ZeroLengthError> {
+        let linear = Matrix::<T, 3, 3>::from_axis_angle(axis, angle)?;
+        Ok(Affine::new(linear, Vector::new([T::default(); 3])).to_homogeneous())
+    }
+}
+
+/// The affine map `x ↦ linear * x + translation`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine<T, const N: usize> {
+    pub linear: Matrix<T, N, N>,
+    pub translation: Vector<T, N>,
+}
+
+impl<T, const N: usize> Affine<T, N> {
+    pub fn new(linear: Matrix<T, N, N>, translation: Vector<T, N>) -> Self {
+        Self {
+            linear,
+            translation,
+        }
+    }
+}
+
+impl<T: Ring, const N: usize> Affine<T, N> {
+    pub fn identity() -> Self {
+        Self::new(Matrix::identity(), Vector::new([T::default(); N]))
+    }
+
+    /// Ignores the last row.
+    pub fn from_homogeneous<const M: usize>(matrix: &Matrix<T, M, M>) -> Self {
+        const { assert!(M == N + 1, "homogeneous matrix size must be N + 1") }
+        Self::new(
+            Matrix::new(array::from_fn(|i| array::from_fn(|j| matrix[i][j]))),
+            Vector::new(array::from_fn(|i| matrix[i][N])),
+        )
+    }
+
+    pub fn to_homogeneous<const M: usize>(&self) -> Matrix<T, M, M> {
+        const { assert!(M == N + 1, "homogeneous matrix size must be N + 1") }
+        let mut result = Matrix::<T, M, M>::identity();
+        for i in 0..N {
+            result[i][..N].copy_from_slice(&self.linear.data[i]);
+            result[i][N] = self.translation.data[i];
+        }
+        result
+    }
+
+    pub fn transform_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        self.linear * *point + self.translation
+    }
+
+    pub fn transform_vector(&self, vector: &Vector<T, N>) -> Vector<T, N> {
+        self.linear * *vector
+    }
+
+    /// Assumes `linear` is orthogonal.
+    pub fn rigid_inverse(&self) -> Self {
+        let linear = self.linear.transpose();
+        let translation = linear * self.translation;
+        Self::new(linear, translation * (T::default() - T::one()))
+    }
+}
+
+impl<T: Field, const N: usize> Affine<T, N> {
+    pub fn inverse(&self) -> Option<Self> {
+        let linear = self.linear.inverse()?;
+        let translation = -linear * self.translation;
+        Some(Self::new(linear, translation))
+    }
+}
+
+/// `a * b` applies `b` first.
+impl<T: Ring, const N: usize> Mul for Affine<T, N> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.linear * rhs.linear,
+            self.linear * rhs.translation + self.translation,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translation_moves_points_not_vectors() {
+        let t: Matrix<i32, 3, 3> = Matrix::translation(&Vector::new([2, -1]));
+        assert_eq!(
+            t.transform_vector(&Vector::new([1, 1])),
+            Vector::new([1, 1])
+        );
+        let a = Affine::<i32, 2>::from_homogeneous(&t);
+        assert_eq!(a.transform_point(&Vector::new([1, 1])), Vector::new([3, 0]));
+        assert_eq!(a.to_homogeneous(), t);
+    }
+
+    #[test]
+    fn test_affine_inverse() {
+        let a = Affine::new(
+            Matrix::new([[2.0, 0.0], [0.0, 4.0]]),
+            Vector::new([1.0, 2.0]),
+        );
+        let inv = a.inverse().unwrap();
+        assert_eq!(inv * a, Affine::identity());
+    }
+}
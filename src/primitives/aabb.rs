@@ -0,0 +1,136 @@
+use super::line::Ray;
+use crate::scalar::Real;
+use crate::vector::Vector;
+use core::array;
+
+/// An axis-aligned box spanning `min` to `max` in every coordinate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb<T, const N: usize> {
+    pub min: Vector<T, N>,
+    pub max: Vector<T, N>,
+}
+
+impl<T: Real, const N: usize> Aabb<T, N> {
+    /// The box with `a` and `b` as opposite corners, in either order.
+    pub fn new(a: Vector<T, N>, b: Vector<T, N>) -> Self {
+        Self {
+            min: Vector::new(array::from_fn(|i| min(a.data[i], b.data[i]))),
+            max: Vector::new(array::from_fn(|i| max(a.data[i], b.data[i]))),
+        }
+    }
+
+    pub fn from_points(points: &[Vector<T, N>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Self::new(*first, *first), |acc, p| {
+            Self::new(
+                Vector::new(array::from_fn(|i| min(acc.min.data[i], p.data[i]))),
+                Vector::new(array::from_fn(|i| max(acc.max.data[i], p.data[i]))),
+            )
+        }))
+    }
+
+    pub fn center(&self) -> Vector<T, N> {
+        self.min.lerp(&self.max, T::one() / (T::one() + T::one()))
+    }
+
+    pub fn size(&self) -> Vector<T, N> {
+        self.max - self.min
+    }
+
+    pub fn contains(&self, point: &Vector<T, N>) -> bool {
+        (0..N).all(|i| self.min.data[i] <= point.data[i] && point.data[i] <= self.max.data[i])
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..N)
+            .all(|i| self.min.data[i] <= other.max.data[i] && other.min.data[i] <= self.max.data[i])
+    }
+
+    pub fn closest_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        Vector::new(array::from_fn(|i| {
+            max(self.min.data[i], min(point.data[i], self.max.data[i]))
+        }))
+    }
+
+    /// Inside the box, minus the distance to the nearest face.
+    pub fn signed_distance(&self, point: &Vector<T, N>) -> T {
+        if !self.contains(point) {
+            return self.closest_point(point).distance(point);
+        }
+        let depth = (0..N)
+            .map(|i| {
+                min(
+                    point.data[i] - self.min.data[i],
+                    self.max.data[i] - point.data[i],
+                )
+            })
+            .fold(None, |acc: Option<T>, d| Some(acc.map_or(d, |a| min(a, d))));
+        -depth.unwrap_or_default()
+    }
+}
+
+impl<T: Real, const N: usize> Ray<T, N> {
+    /// Entry and exit parameters; a ray starting inside enters at 0.
+    pub fn intersect_aabb(&self, aabb: &Aabb<T, N>) -> Option<(T, T)> {
+        let mut enter = T::default();
+        let mut exit: Option<T> = None;
+        for i in 0..N {
+            let (origin, direction) = (self.origin.data[i], self.direction.data[i]);
+            let (lo, hi) = (aabb.min.data[i], aabb.max.data[i]);
+            if direction == T::default() {
+                if origin < lo || origin > hi {
+                    return None;
+                }
+                continue;
+            }
+            let (t1, t2) = ((lo - origin) / direction, (hi - origin) / direction);
+            let (near, far) = if t1 <= t2 { (t1, t2) } else { (t2, t1) };
+            enter = max(enter, near);
+            let leave = exit.map_or(far, |e| min(e, far));
+            if enter > leave {
+                return None;
+            }
+            exit = Some(leave);
+        }
+        Some((enter, exit.unwrap_or(enter)))
+    }
+}
+
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corners_in_any_order() {
+        let aabb = Aabb::new(Vector::new([2.0, -1.0]), Vector::new([0.0, 3.0]));
+        assert_eq!(aabb.min, Vector::new([0.0, -1.0]));
+        assert_eq!(aabb.max, Vector::new([2.0, 3.0]));
+        assert_eq!(aabb.signed_distance(&Vector::new([1.0, 0.0])), -1.0);
+        assert_eq!(aabb.signed_distance(&Vector::new([5.0, 7.0])), 5.0);
+    }
+
+    #[test]
+    fn test_ray_slab() {
+        let aabb = Aabb::new(Vector::new([1.0, 1.0]), Vector::new([3.0, 2.0]));
+        let ray = Ray::new(Vector::new([0.0, 1.5]), Vector::new([1.0, 0.0]));
+        assert_eq!(ray.intersect_aabb(&aabb), Some((1.0, 3.0)));
+        let miss = Ray::new(Vector::new([0.0, 2.5]), Vector::new([1.0, 0.0]));
+        assert_eq!(miss.intersect_aabb(&aabb), None);
+    }
+}
@@ -0,0 +1,188 @@
+use super::clamp_unit;
+use crate::scalar::Real;
+use crate::vector::Vector;
+
+/// The infinite line `origin + t * direction`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Line<T, const N: usize> {
+    pub origin: Vector<T, N>,
+    pub direction: Vector<T, N>,
+}
+
+/// The half-line `origin + t * direction` for `t >= 0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray<T, const N: usize> {
+    pub origin: Vector<T, N>,
+    pub direction: Vector<T, N>,
+}
+
+/// The points `start + t * (end - start)` for `t` in `[0, 1]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Segment<T, const N: usize> {
+    pub start: Vector<T, N>,
+    pub end: Vector<T, N>,
+}
+
+impl<T, const N: usize> Line<T, N> {
+    pub fn new(origin: Vector<T, N>, direction: Vector<T, N>) -> Self {
+        Self { origin, direction }
+    }
+}
+
+impl<T, const N: usize> Ray<T, N> {
+    pub fn new(origin: Vector<T, N>, direction: Vector<T, N>) -> Self {
+        Self { origin, direction }
+    }
+}
+
+impl<T, const N: usize> Segment<T, N> {
+    pub fn new(start: Vector<T, N>, end: Vector<T, N>) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T: Real, const N: usize> Line<T, N> {
+    pub fn through(a: &Vector<T, N>, b: &Vector<T, N>) -> Self {
+        Self::new(*a, *b - *a)
+    }
+
+    pub fn point_at(&self, t: T) -> Vector<T, N> {
+        self.origin + self.direction * t
+    }
+
+    /// Zero for a degenerate line.
+    pub fn closest_parameter(&self, point: &Vector<T, N>) -> T {
+        let length_squared = self.direction.dot(&self.direction);
+        if length_squared == T::default() {
+            return T::default();
+        }
+        self.direction.dot(&(*point - self.origin)) / length_squared
+    }
+
+    pub fn closest_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        self.point_at(self.closest_parameter(point))
+    }
+
+    pub fn distance(&self, point: &Vector<T, N>) -> T {
+        self.closest_point(point).distance(point)
+    }
+}
+
+impl<T: Real, const N: usize> Ray<T, N> {
+    pub fn point_at(&self, t: T) -> Vector<T, N> {
+        self.origin + self.direction * t
+    }
+
+    pub fn closest_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        let t = Line::new(self.origin, self.direction).closest_parameter(point);
+        if t < T::default() {
+            self.origin
+        } else {
+            self.point_at(t)
+        }
+    }
+
+    pub fn distance(&self, point: &Vector<T, N>) -> T {
+        self.closest_point(point).distance(point)
+    }
+}
+
+impl<T: Real, const N: usize> Segment<T, N> {
+    pub fn length(&self) -> T {
+        self.start.distance(&self.end)
+    }
+
+    pub fn point_at(&self, t: T) -> Vector<T, N> {
+        self.start.lerp(&self.end, t)
+    }
+
+    pub fn closest_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        let t = Line::through(&self.start, &self.end).closest_parameter(point);
+        self.point_at(clamp_unit(t))
+    }
+
+    pub fn distance(&self, point: &Vector<T, N>) -> T {
+        self.closest_point(point).distance(point)
+    }
+
+    /// Parallel segments yield one of the equally close pairs.
+    pub fn closest_points(&self, other: &Self) -> (Vector<T, N>, Vector<T, N>) {
+        let zero = T::default();
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let r = self.start - other.start;
+        let a = d1.dot(&d1);
+        let e = d2.dot(&d2);
+        let f = d2.dot(&r);
+        let (s, t) = if a == zero && e == zero {
+            (zero, zero)
+        } else if a == zero {
+            (zero, clamp_unit(f / e))
+        } else {
+            let c = d1.dot(&r);
+            if e == zero {
+                (clamp_unit(-c / a), zero)
+            } else {
+                let b = d1.dot(&d2);
+                // `denom / (a * e)` is the squared sine of the angle between
+                // the segments, so the parallel test does not depend on scale.
+                let denom = a * e - b * b;
+                let s = if (denom / (a * e)).is_negligible() {
+                    zero
+                } else {
+                    clamp_unit((b * f - c * e) / denom)
+                };
+                let t = (b * s + f) / e;
+                if t < zero {
+                    (clamp_unit(-c / a), zero)
+                } else if t > T::one() {
+                    (clamp_unit((b - c) / a), T::one())
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (self.point_at(s), other.point_at(t))
+    }
+
+    pub fn distance_to_segment(&self, other: &Self) -> T {
+        let (p, q) = self.closest_points(other);
+        p.distance(&q)
+    }
+
+    pub fn intersect(&self, other: &Self) -> Option<Vector<T, N>> {
+        let (p, q) = self.closest_points(other);
+        if p.distance(&q).is_negligible() {
+            Some(p)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_closest_point() {
+        let line = Line::through(&Vector::new([0.0, 0.0]), &Vector::new([2.0, 0.0]));
+        assert_eq!(
+            line.closest_point(&Vector::new([5.0, 3.0])),
+            Vector::new([5.0, 0.0])
+        );
+        assert_eq!(line.distance(&Vector::new([5.0, 3.0])), 3.0);
+        let ray = Ray::new(line.origin, line.direction);
+        assert_eq!(
+            ray.closest_point(&Vector::new([-5.0, 3.0])),
+            Vector::new([0.0, 0.0])
+        );
+    }
+
+    #[test]
+    fn test_segments_cross() {
+        let a = Segment::new(Vector::new([0.0, 0.0]), Vector::new([2.0, 2.0]));
+        let b = Segment::new(Vector::new([0.0, 2.0]), Vector::new([2.0, 0.0]));
+        assert_eq!(a.intersect(&b), Some(Vector::new([1.0, 1.0])));
+    }
+}
@@ -0,0 +1,25 @@
+pub mod aabb;
+pub mod line;
+pub mod plane;
+pub mod simplex;
+pub mod sphere;
+pub mod triangle;
+
+pub use aabb::Aabb;
+pub use line::{Line, Ray, Segment};
+pub use plane::Plane;
+pub use simplex::{Simplex, Tetrahedron};
+pub use sphere::Sphere;
+pub use triangle::Triangle;
+
+use crate::scalar::Real;
+
+fn clamp_unit<T: Real>(value: T) -> T {
+    if value < T::default() {
+        T::default()
+    } else if value > T::one() {
+        T::one()
+    } else {
+        value
+    }
+}
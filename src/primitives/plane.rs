@@ -0,0 +1,93 @@
+use super::line::Ray;
+use crate::scalar::Real;
+use crate::vector::geometry::ZeroLengthError;
+use crate::vector::Vector;
+
+/// The hyperplane `normal · x = offset`, with `normal` of unit length.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane<T, const N: usize> {
+    pub normal: Vector<T, N>,
+    pub offset: T,
+}
+
+impl<T: Real, const N: usize> Plane<T, N> {
+    /// Normalizes `normal`, scaling `offset` to match.
+    pub fn new(normal: Vector<T, N>, offset: T) -> Result<Self, ZeroLengthError> {
+        let length = normal.magnitude();
+        let normal = normal.normalize()?;
+        Ok(Self {
+            normal,
+            offset: offset / length,
+        })
+    }
+
+    pub fn from_point_normal(
+        point: &Vector<T, N>,
+        normal: &Vector<T, N>,
+    ) -> Result<Self, ZeroLengthError> {
+        let normal = normal.normalize()?;
+        Ok(Self {
+            normal,
+            offset: normal.dot(point),
+        })
+    }
+
+    /// Positive on the side `normal` points to.
+    pub fn signed_distance(&self, point: &Vector<T, N>) -> T {
+        self.normal.dot(point) - self.offset
+    }
+
+    pub fn closest_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        *point - self.normal * self.signed_distance(point)
+    }
+}
+
+impl<T: Real> Plane<T, 3> {
+    /// The normal follows the right-hand rule.
+    pub fn from_points(
+        a: &Vector<T, 3>,
+        b: &Vector<T, 3>,
+        c: &Vector<T, 3>,
+    ) -> Result<Self, ZeroLengthError> {
+        Self::from_point_normal(a, &(*b - *a).cross(&(*c - *a)))
+    }
+}
+
+impl<T: Real, const N: usize> Ray<T, N> {
+    /// `None` if the ray is parallel to the plane or points away.
+    pub fn intersect_plane(&self, plane: &Plane<T, N>) -> Option<T> {
+        let denom = plane.normal.dot(&self.direction);
+        if denom.is_negligible() {
+            return None;
+        }
+        let t = (plane.offset - plane.normal.dot(&self.origin)) / denom;
+        if t < T::default() {
+            None
+        } else {
+            Some(t)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed_distance() {
+        let plane = Plane::new(Vector::new([0.0, 0.0, 2.0]), 2.0).unwrap();
+        assert_eq!(plane.offset, 1.0);
+        assert_eq!(plane.signed_distance(&Vector::new([5.0, 5.0, 3.0])), 2.0);
+        assert_eq!(plane.signed_distance(&Vector::new([5.0, 5.0, 0.0])), -1.0);
+    }
+
+    #[test]
+    fn test_ray_plane() {
+        let plane =
+            Plane::from_point_normal(&Vector::new([0.0, 1.0]), &Vector::new([0.0, 1.0])).unwrap();
+        let ray = Ray::new(Vector::new([3.0, -1.0]), Vector::new([0.0, 0.5]));
+        assert_eq!(ray.intersect_plane(&plane), Some(4.0));
+        let away = Ray::new(Vector::new([3.0, -1.0]), Vector::new([0.0, -1.0]));
+        assert_eq!(away.intersect_plane(&plane), None);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::matrix::Matrix;
+use crate::scalar::Real;
+use crate::vector::Vector;
+use core::array;
+
+/// The convex hull of `K` points in `N` dimensions. `barycentric`, `contains`
+/// and the volumes need `K == N + 1`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Simplex<T, const N: usize, const K: usize> {
+    pub vertices: [Vector<T, N>; K],
+}
+
+pub type Tetrahedron<T> = Simplex<T, 3, 4>;
+
+impl<T, const N: usize, const K: usize> Simplex<T, N, K> {
+    pub fn new(vertices: [Vector<T, N>; K]) -> Self {
+        Self { vertices }
+    }
+}
+
+impl<T: Real, const N: usize, const K: usize> Simplex<T, N, K> {
+    pub fn point_at(&self, weights: [T; K]) -> Vector<T, N> {
+        let zero = Vector::new([T::default(); N]);
+        (0..K).fold(zero, |acc, i| acc + self.vertices[i] * weights[i])
+    }
+
+    pub fn barycentric(&self, p: &Vector<T, N>) -> Option<[T; K]> {
+        const { assert!(K == N + 1, "a full-dimensional simplex has N + 1 vertices") }
+        let (edges, scale) = self.normalized_edges()?;
+        let local = edges.solve(&((*p - self.vertices[0]) * (T::one() / scale)))?;
+        let mut weights = [T::default(); K];
+        weights[0] = T::one() - local.data.iter().copied().sum::<T>();
+        weights[1..].copy_from_slice(&local.data);
+        Some(weights)
+    }
+
+    pub fn contains(&self, p: &Vector<T, N>) -> bool {
+        self.barycentric(p).is_some_and(|weights| {
+            weights
+                .iter()
+                .all(|&w| w >= T::default() || w.is_negligible())
+        })
+    }
+
+    /// Positive when the edges from the first vertex are right-handed.
+    pub fn signed_volume(&self) -> T {
+        const { assert!(K == N + 1, "a full-dimensional simplex has N + 1 vertices") }
+        let (mut factorial, mut k) = (T::one(), T::one());
+        for _ in 1..N {
+            k = k + T::one();
+            factorial = factorial * k;
+        }
+        let (edges, scale) = match self.normalized_edges() {
+            Some(normalized) => normalized,
+            None => return T::default(),
+        };
+        let scale_n = (0..N).fold(T::one(), |acc, _| acc * scale);
+        edges.determinant() * scale_n / factorial
+    }
+
+    pub fn volume(&self) -> T {
+        self.signed_volume().abs()
+    }
+
+    /// The edges from the first vertex as columns, divided by their largest
+    /// entry, and that entry.
+    fn normalized_edges(&self) -> Option<(Matrix<T, N, N>, T)> {
+        let origin = self.vertices[0];
+        let edges: Matrix<T, N, N> = Matrix::new(array::from_fn(|i| {
+            array::from_fn(|j| self.vertices[j + 1].data[i] - origin.data[i])
+        }));
+        let scale = edges.data.iter().flatten().fold(T::default(), |acc, &x| {
+            if x.abs() > acc {
+                x.abs()
+            } else {
+                acc
+            }
+        });
+        if scale == T::default() {
+            return None;
+        }
+        Some((edges * (T::one() / scale), scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_tetrahedron() {
+        let t: Tetrahedron<f64> = Simplex::new([
+            Vector::new([0.0, 0.0, 0.0]),
+            Vector::new([1.0, 0.0, 0.0]),
+            Vector::new([0.0, 1.0, 0.0]),
+            Vector::new([0.0, 0.0, 1.0]),
+        ]);
+        assert!((t.signed_volume() - 1.0 / 6.0).abs() < 1e-15);
+        let p = Vector::new([0.1, 0.2, 0.3]);
+        let w = t.barycentric(&p).unwrap();
+        assert!((w[0] - 0.4).abs() < 1e-15);
+        assert!(t.point_at(w).distance(&p) < 1e-15);
+        assert!(!t.contains(&Vector::new([0.5, 0.5, 0.5])));
+    }
+}
@@ -0,0 +1,68 @@
+use crate::scalar::Real;
+use crate::vector::Vector;
+
+/// The ball of points within `radius` of `center`; a disc when `N` is 2.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sphere<T, const N: usize> {
+    pub center: Vector<T, N>,
+    pub radius: T,
+}
+
+impl<T, const N: usize> Sphere<T, N> {
+    pub fn new(center: Vector<T, N>, radius: T) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<T: Real, const N: usize> Sphere<T, N> {
+    pub fn contains(&self, point: &Vector<T, N>) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+
+    /// Negative inside the sphere, zero on its surface.
+    pub fn signed_distance(&self, point: &Vector<T, N>) -> T {
+        self.center.distance(point) - self.radius
+    }
+
+    /// For the center itself, the surface point along the first axis.
+    pub fn closest_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        match (*point - self.center).normalize() {
+            Ok(direction) => self.center + direction * self.radius,
+            Err(_) => {
+                let mut offset = Vector::new([T::default(); N]);
+                offset.data[0] = self.radius;
+                self.center + offset
+            }
+        }
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        let reach = self.radius + other.radius;
+        self.center.distance_squared(&other.center) <= reach * reach
+    }
+
+    pub fn penetration_depth(&self, other: &Self) -> Option<T> {
+        let depth = self.radius + other.radius - self.center.distance(&other.center);
+        if depth < T::default() {
+            None
+        } else {
+            Some(depth)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sphere_sphere() {
+        let a = Sphere::new(Vector::new([0.0, 0.0, 0.0]), 1.0);
+        let b = Sphere::new(Vector::new([3.0, 0.0, 0.0]), 1.5);
+        assert!(!a.intersects(&b));
+        assert_eq!(a.penetration_depth(&b), None);
+        let c = Sphere::new(Vector::new([2.0, 0.0, 0.0]), 1.5);
+        assert!(a.intersects(&c));
+        assert_eq!(a.penetration_depth(&c), Some(0.5));
+    }
+}
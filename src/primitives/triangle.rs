@@ -0,0 +1,244 @@
+use super::line::Ray;
+use crate::matrix::Matrix;
+use crate::scalar::Real;
+use crate::vector::geometry::ZeroLengthError;
+use crate::vector::Vector;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Triangle<T, const N: usize> {
+    pub a: Vector<T, N>,
+    pub b: Vector<T, N>,
+    pub c: Vector<T, N>,
+}
+
+impl<T, const N: usize> Triangle<T, N> {
+    pub fn new(a: Vector<T, N>, b: Vector<T, N>, c: Vector<T, N>) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl<T: Real, const N: usize> Triangle<T, N> {
+    pub fn closest_point(&self, p: &Vector<T, N>) -> Vector<T, N> {
+        let zero = T::default();
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+        let ap = *p - a;
+        let d1 = ab.dot(&ap);
+        let d2 = ac.dot(&ap);
+        if d1 <= zero && d2 <= zero {
+            return a;
+        }
+        let bp = *p - b;
+        let d3 = ab.dot(&bp);
+        let d4 = ac.dot(&bp);
+        if d3 >= zero && d4 <= d3 {
+            return b;
+        }
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= zero && d1 >= zero && d3 <= zero {
+            return a + ab * (d1 / (d1 - d3));
+        }
+        let cp = *p - c;
+        let d5 = ab.dot(&cp);
+        let d6 = ac.dot(&cp);
+        if d6 >= zero && d5 <= d6 {
+            return c;
+        }
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= zero && d2 >= zero && d6 <= zero {
+            return a + ac * (d2 / (d2 - d6));
+        }
+        let va = d3 * d6 - d5 * d4;
+        if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+        let denom = T::one() / (va + vb + vc);
+        a + ab * (vb * denom) + ac * (vc * denom)
+    }
+
+    pub fn distance(&self, p: &Vector<T, N>) -> T {
+        self.closest_point(p).distance(p)
+    }
+
+    pub fn point_at(&self, weights: [T; 3]) -> Vector<T, N> {
+        let [u, v, w] = weights;
+        self.a * u + self.b * v + self.c * w
+    }
+
+    /// Weights of `p` projected onto the plane of the triangle.
+    pub fn barycentric(&self, p: &Vector<T, N>) -> Option<[T; 3]> {
+        let (gram, scale) = self.normalized_gram()?;
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let ap = *p - self.a;
+        let rhs = Vector::new([ab.dot(&ap) / scale, ac.dot(&ap) / scale]);
+        let [v, w] = gram.solve(&rhs)?.data;
+        Some([T::one() - v - w, v, w])
+    }
+
+    pub fn contains(&self, p: &Vector<T, N>) -> bool {
+        let size = (self.b - self.a).magnitude() + (self.c - self.a).magnitude();
+        self.barycentric(p).is_some_and(|weights| {
+            weights
+                .iter()
+                .all(|&w| w >= T::default() || w.is_negligible())
+                && (self.point_at(weights).distance(p) / size).is_negligible()
+        })
+    }
+
+    pub fn area(&self) -> T {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let gram = ab.dot(&ab) * ac.dot(&ac) - ab.dot(&ac) * ab.dot(&ac);
+        let half = T::one() / (T::one() + T::one());
+        if gram > T::default() {
+            gram.sqrt() * half
+        } else {
+            T::default()
+        }
+    }
+
+    pub fn circumcenter(&self) -> Option<Vector<T, N>> {
+        let (gram, scale) = self.normalized_gram()?;
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let half = T::one() / (T::one() + T::one());
+        let rhs = Vector::new([ab.dot(&ab) * half / scale, ac.dot(&ac) * half / scale]);
+        let [s, t] = gram.solve(&rhs)?.data;
+        Some(self.a + ab * s + ac * t)
+    }
+
+    pub fn incenter(&self) -> Option<Vector<T, N>> {
+        if self.normalized_gram()?.0.determinant().is_negligible() {
+            return None;
+        }
+        let weights = [
+            self.b.distance(&self.c),
+            self.c.distance(&self.a),
+            self.a.distance(&self.b),
+        ];
+        let perimeter = weights[0] + weights[1] + weights[2];
+        Some(self.point_at(weights.map(|w| w / perimeter)))
+    }
+
+    /// The Gram matrix of the edges from `a`, divided by its trace, and that trace.
+    fn normalized_gram(&self) -> Option<(Matrix<T, 2, 2>, T)> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let scale = ab.dot(&ab) + ac.dot(&ac);
+        if scale == T::default() {
+            return None;
+        }
+        let gram = Matrix::new([[ab.dot(&ab), ab.dot(&ac)], [ab.dot(&ac), ac.dot(&ac)]]);
+        Some((gram * (T::one() / scale), scale))
+    }
+}
+
+impl<T: Real> Triangle<T, 2> {
+    /// Positive when `a`, `b`, `c` run counterclockwise.
+    pub fn signed_area(&self) -> T {
+        (self.b - self.a).perp_dot(&(self.c - self.a)) / (T::one() + T::one())
+    }
+}
+
+impl<T: Real> Triangle<T, 3> {
+    /// Seen from its tip, `a`, `b`, `c` run counterclockwise.
+    pub fn normal(&self) -> Result<Vector<T, 3>, ZeroLengthError> {
+        (self.b - self.a).cross(&(self.c - self.a)).normalize()
+    }
+}
+
+impl<T: Real> Ray<T, 3> {
+    /// Both faces count; rays in the plane of the triangle miss.
+    pub fn intersect_triangle(&self, triangle: &Triangle<T, 3>) -> Option<T> {
+        let zero = T::default();
+        let e1 = triangle.b - triangle.a;
+        let e2 = triangle.c - triangle.a;
+        let p = self.direction.cross(&e2);
+        let det = e1.dot(&p);
+        let scale = e1.magnitude() * e2.magnitude() * self.direction.magnitude();
+        if scale == zero || (det / scale).is_negligible() {
+            return None;
+        }
+        let inv = T::one() / det;
+        let s = self.origin - triangle.a;
+        let u = s.dot(&p) * inv;
+        if u < zero || u > T::one() {
+            return None;
+        }
+        let q = s.cross(&e1);
+        let v = self.direction.dot(&q) * inv;
+        if v < zero || u + v > T::one() {
+            return None;
+        }
+        let t = e2.dot(&q) * inv;
+        if t < zero {
+            None
+        } else {
+            Some(t)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_point_regions() {
+        let t = Triangle::new(
+            Vector::new([0.0, 0.0]),
+            Vector::new([4.0, 0.0]),
+            Vector::new([0.0, 4.0]),
+        );
+        assert_eq!(
+            t.closest_point(&Vector::new([-1.0, -1.0])),
+            Vector::new([0.0, 0.0])
+        );
+        assert_eq!(
+            t.closest_point(&Vector::new([2.0, -3.0])),
+            Vector::new([2.0, 0.0])
+        );
+        assert_eq!(
+            t.closest_point(&Vector::new([3.0, 3.0])),
+            Vector::new([2.0, 2.0])
+        );
+        assert_eq!(
+            t.closest_point(&Vector::new([1.0, 1.0])),
+            Vector::new([1.0, 1.0])
+        );
+    }
+
+    #[test]
+    fn test_barycentric_round_trip() {
+        let t = Triangle::new(
+            Vector::new([1.0, 0.0, 0.0]),
+            Vector::new([0.0, 2.0, 0.0]),
+            Vector::new([0.0, 0.0, 3.0]),
+        );
+        let w = t.barycentric(&t.point_at([0.2, 0.3, 0.5])).unwrap();
+        assert!((w[0] - 0.2).abs() < 1e-12 && (w[1] - 0.3).abs() < 1e-12);
+        assert!(t.contains(&t.point_at([0.2, 0.3, 0.5])));
+        assert!(!t.contains(&Vector::new([0.0, 0.0, 0.0])));
+    }
+
+    #[test]
+    fn test_moller_trumbore() {
+        let t = Triangle::new(
+            Vector::new([0.0, 0.0, 0.0]),
+            Vector::new([1.0, 0.0, 0.0]),
+            Vector::new([0.0, 1.0, 0.0]),
+        );
+        let ray = Ray::new(
+            Vector::new([0.25, 0.25, 2.0]),
+            Vector::new([0.0, 0.0, -1.0]),
+        );
+        assert_eq!(ray.intersect_triangle(&t), Some(2.0));
+        let miss = Ray::new(
+            Vector::new([0.75, 0.75, 2.0]),
+            Vector::new([0.0, 0.0, -1.0]),
+        );
+        assert_eq!(miss.intersect_triangle(&t), None);
+    }
+}
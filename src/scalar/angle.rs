@@ -0,0 +1,180 @@
+use super::Real;
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A plane angle, stored in radians.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Default, Debug)]
+pub struct Angle<T> {
+    radians: T,
+}
+
+/// An angle in degrees, convertible into `Angle`.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Default, Debug)]
+pub struct Deg<T>(pub T);
+
+/// An angle in radians, convertible into `Angle`.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Default, Debug)]
+pub struct Rad<T>(pub T);
+
+impl<T> Angle<T> {
+    pub fn from_radians(radians: T) -> Self {
+        Self { radians }
+    }
+
+    pub fn radians(self) -> T {
+        self.radians
+    }
+}
+
+impl<T: Real> Angle<T> {
+    pub fn from_degrees(degrees: T) -> Self {
+        Self::from_radians(degrees.to_radians())
+    }
+
+    pub fn degrees(self) -> T {
+        self.radians / T::one().to_radians()
+    }
+
+    pub fn full_turn() -> Self {
+        Self::from_radians(T::pi() + T::pi())
+    }
+
+    pub fn sin(self) -> T {
+        self.radians.sin()
+    }
+
+    pub fn cos(self) -> T {
+        self.radians.cos()
+    }
+
+    pub fn tan(self) -> T {
+        self.radians.sin() / self.radians.cos()
+    }
+
+    pub fn sin_cos(self) -> (T, T) {
+        (self.sin(), self.cos())
+    }
+
+    /// `atan2(y, x)` as an angle in `(-π, π]`.
+    pub fn atan2(y: T, x: T) -> Self {
+        Self::from_radians(y.atan2(x))
+    }
+
+    /// The equivalent angle in `(-π, π]`.
+    pub fn wrap_signed(self) -> Self {
+        let pi = T::pi();
+        if -pi < self.radians && self.radians <= pi {
+            return self;
+        }
+        let wrapped = Self::atan2(self.sin(), self.cos());
+        // `sin` of an odd multiple of π may round to a tiny negative value.
+        if wrapped.radians <= -pi {
+            wrapped + Self::full_turn()
+        } else {
+            wrapped
+        }
+    }
+
+    /// The equivalent angle in `[0, 2π)`.
+    pub fn wrap_positive(self) -> Self {
+        let wrapped = self.wrap_signed();
+        if wrapped.radians >= T::default() {
+            return wrapped;
+        }
+        let shifted = wrapped + Self::full_turn();
+        // A tiny negative angle can round up to a full turn.
+        if shifted >= Self::full_turn() {
+            Self::default()
+        } else {
+            shifted
+        }
+    }
+}
+
+impl<T: Real> From<Deg<T>> for Angle<T> {
+    fn from(degrees: Deg<T>) -> Self {
+        Self::from_degrees(degrees.0)
+    }
+}
+
+impl<T> From<Rad<T>> for Angle<T> {
+    fn from(radians: Rad<T>) -> Self {
+        Self::from_radians(radians.0)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Angle<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_radians(self.radians + rhs.radians)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Angle<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_radians(self.radians - rhs.radians)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Angle<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::from_radians(-self.radians)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<T> for Angle<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::from_radians(self.radians * rhs)
+    }
+}
+
+impl<T: Div<Output = T>> Div<T> for Angle<T> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self::Output {
+        Self::from_radians(self.radians / rhs)
+    }
+}
+
+/// The ratio of two angles.
+impl<T: Div<Output = T>> Div for Angle<T> {
+    type Output = T;
+    fn div(self, rhs: Self) -> T {
+        self.radians / rhs.radians
+    }
+}
+
+impl<T: Add<Output = T> + Default> Sum for Angle<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Angle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} rad", self.radians)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units() {
+        let right: Angle<f64> = Deg(90.0).into();
+        assert_eq!(right, Angle::from(Rad(core::f64::consts::FRAC_PI_2)));
+        assert_eq!(right.degrees(), 90.0);
+        assert_eq!(right * 2.0, Angle::from_radians(core::f64::consts::PI));
+    }
+
+    #[test]
+    fn test_wrap() {
+        let a = Angle::from_degrees(270.0_f64);
+        assert!((a.wrap_signed().degrees() + 90.0).abs() < 1e-12);
+        assert!((Angle::from_degrees(-90.0_f64).wrap_positive().degrees() - 270.0).abs() < 1e-12);
+    }
+}
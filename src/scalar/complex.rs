@@ -0,0 +1,141 @@
+use super::{Conjugate, Field, One};
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    pub fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T>> Complex<T> {
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl Complex<f64> {
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+
+    pub fn abs(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    pub fn arg(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+}
+
+impl<T: Default> From<T> for Complex<T> {
+    fn from(re: T) -> Self {
+        Self::new(re, T::default())
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Conjugate for Complex<T> {
+    fn conjugate(&self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+}
+
+impl<T: One + Default> One for Complex<T> {
+    fn one() -> Self {
+        Self::new(T::one(), T::default())
+    }
+}
+
+impl<T: Field> Field for Complex<T> {
+    fn is_negligible(&self) -> bool {
+        self.re.is_negligible() && self.im.is_negligible()
+    }
+}
+
+impl<T: Add<Output = T>> Add for Complex<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Complex<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Mul for Complex<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T> Div for Complex<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        let denom = rhs.norm_sqr();
+        Self::new(
+            (self.re * rhs.re + self.im * rhs.im) / denom,
+            (self.im * rhs.re - self.re * rhs.im) / denom,
+        )
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<T: Add<Output = T> + Default> Sum for Complex<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
+
+impl<T: fmt::Display + PartialOrd + Default + Copy + Neg<Output = T>> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < T::default() {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mul_div() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert_eq!((a * b) / b, a);
+    }
+
+    #[test]
+    fn test_conjugate() {
+        let a = Complex::new(3, 4);
+        assert_eq!(a * a.conjugate(), Complex::new(25, 0));
+        assert_eq!(format!("{}", a.conjugate()), "3-4i");
+    }
+}
@@ -0,0 +1,218 @@
+use super::{Conjugate, Field, One};
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use core::array;
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// `re + Σ eps[k] εₖ` with `εⱼ εₖ = 0`, carrying `K` derivatives through arithmetic.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DualN<T, const K: usize> {
+    pub re: T,
+    pub eps: [T; K],
+}
+
+/// A dual number with a single derivative component.
+pub type Dual<T> = DualN<T, 1>;
+
+impl<T, const K: usize> DualN<T, K> {
+    pub fn new(re: T, eps: [T; K]) -> Self {
+        Self { re, eps }
+    }
+}
+
+impl<T: Copy + Default, const K: usize> DualN<T, K> {
+    pub fn constant(re: T) -> Self {
+        Self::new(re, [T::default(); K])
+    }
+}
+
+impl<T: Copy + Default + One, const K: usize> DualN<T, K> {
+    /// The `index`-th independent variable, with value `re`.
+    pub fn variable(re: T, index: usize) -> Self {
+        let mut result = Self::constant(re);
+        result.eps[index] = T::one();
+        result
+    }
+}
+
+impl<T: Copy> Dual<T> {
+    pub fn derivative(&self) -> T {
+        self.eps[0]
+    }
+}
+
+macro_rules! impl_dual_float {
+    ($($t:ty),*) => {
+        $(
+            impl<const K: usize> DualN<$t, K> {
+                /// Applies a function with value `f` and derivative `df` at `self.re`.
+                fn chain(&self, f: $t, df: $t) -> Self {
+                    Self::new(f, self.eps.map(|e| e * df))
+                }
+
+                pub fn sqrt(&self) -> Self {
+                    let s = self.re.sqrt();
+                    self.chain(s, 0.5 / s)
+                }
+
+                pub fn exp(&self) -> Self {
+                    let e = self.re.exp();
+                    self.chain(e, e)
+                }
+
+                pub fn ln(&self) -> Self {
+                    self.chain(self.re.ln(), 1.0 / self.re)
+                }
+
+                pub fn sin(&self) -> Self {
+                    self.chain(self.re.sin(), self.re.cos())
+                }
+
+                pub fn cos(&self) -> Self {
+                    self.chain(self.re.cos(), -self.re.sin())
+                }
+            }
+        )*
+    };
+}
+
+impl_dual_float!(f32, f64);
+
+impl<T: Copy + Default, const K: usize> Default for DualN<T, K> {
+    fn default() -> Self {
+        Self::constant(T::default())
+    }
+}
+
+impl<T: Copy + Default + One, const K: usize> One for DualN<T, K> {
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+}
+
+impl<T: Copy, const K: usize> Conjugate for DualN<T, K> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Field, const K: usize> Field for DualN<T, K> {
+    fn is_negligible(&self) -> bool {
+        self.re.is_negligible()
+    }
+}
+
+impl<T: Copy + Add<Output = T>, const K: usize> Add for DualN<T, K> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re + rhs.re,
+            array::from_fn(|k| self.eps[k] + rhs.eps[k]),
+        )
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const K: usize> Sub for DualN<T, K> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re - rhs.re,
+            array::from_fn(|k| self.eps[k] - rhs.eps[k]),
+        )
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T>, const K: usize> Mul for DualN<T, K> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re,
+            array::from_fn(|k| self.eps[k] * rhs.re + self.re * rhs.eps[k]),
+        )
+    }
+}
+
+impl<T, const K: usize> Div for DualN<T, K>
+where
+    T: Copy + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        let denom = rhs.re * rhs.re;
+        Self::new(
+            self.re / rhs.re,
+            array::from_fn(|k| (self.eps[k] * rhs.re - self.re * rhs.eps[k]) / denom),
+        )
+    }
+}
+
+impl<T: Copy + Neg<Output = T>, const K: usize> Neg for DualN<T, K> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, self.eps.map(|e| -e))
+    }
+}
+
+impl<T: Copy + Default + Add<Output = T>, const K: usize> Sum for DualN<T, K> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
+
+impl<T: fmt::Display, const K: usize> fmt::Display for DualN<T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.re)?;
+        for (k, e) in self.eps.iter().enumerate() {
+            write!(f, " + {}ε{}", e, k)?;
+        }
+        Ok(())
+    }
+}
+
+/// The Jacobian of `f` at `x`, by forward-mode differentiation.
+pub fn jacobian<T, F, const M: usize, const N: usize>(f: F, x: Vector<T, N>) -> Matrix<T, M, N>
+where
+    T: Copy + Default + One,
+    F: Fn(Vector<DualN<T, N>, N>) -> Vector<DualN<T, N>, M>,
+{
+    let y = f(Vector {
+        data: array::from_fn(|i| DualN::variable(x.data[i], i)),
+    });
+    Matrix {
+        data: array::from_fn(|i| y.data[i].eps),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_product_rule() {
+        let x = Dual::variable(3.0, 0);
+        let y = x * x * x;
+        assert_eq!(y.re, 27.0);
+        assert_eq!(y.derivative(), 27.0);
+    }
+
+    #[test]
+    fn test_elementary_functions_f32() {
+        let x: Dual<f32> = Dual::variable(4.0, 0);
+        assert_eq!(x.sqrt().derivative(), 0.25);
+        assert_eq!(x.ln().derivative(), 0.25);
+        let y: Dual<f32> = Dual::variable(0.0, 0);
+        assert_eq!(y.exp().derivative(), 1.0);
+        assert_eq!(y.sin().derivative(), 1.0);
+        assert_eq!(y.cos().derivative(), 0.0);
+    }
+
+    #[test]
+    fn test_quotient_rule() {
+        let x = Dual::variable(2.0, 0);
+        let y = Dual::constant(1.0) / x;
+        assert_eq!(y.derivative(), -0.25);
+    }
+}
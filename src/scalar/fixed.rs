@@ -0,0 +1,335 @@
+use super::{Conjugate, Field, One, Real};
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Q-format fixed point in `I` with `FRAC` fractional bits. Arithmetic,
+/// division by zero and `one()` without integer bits all saturate.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed<I, const FRAC: u32>(I);
+
+#[allow(non_camel_case_types)]
+pub type Q16_16 = Fixed<i32, 16>;
+#[allow(non_camel_case_types)]
+pub type Q1_31 = Fixed<i32, 31>;
+#[allow(non_camel_case_types)]
+pub type Q32_32 = Fixed<i64, 32>;
+
+// The elementary functions work internally in Q4.60 on `i128`, whatever the
+// storage format, and round once at the end.
+const Q: u32 = 60;
+const PI_Q60: i128 = 3_622_009_729_038_561_421;
+const PI_OVER_180_Q60: i128 = 20_122_276_272_436_452;
+/// The reciprocal of the CORDIC gain, ∏ 1/√(1 + 2⁻²ⁱ).
+const CORDIC_K_Q60: i128 = 700_114_967_507_363_238;
+/// atan(2⁻ⁱ) for i < 20; beyond that atan(2⁻ⁱ) rounds to 2⁻ⁱ in Q4.60.
+const ATAN_Q60: [i128; 20] = [
+    905_502_432_259_640_355,
+    534_549_298_976_576_474,
+    282_441_168_888_798_124,
+    143_371_547_418_228_444,
+    71_963_988_336_308_046,
+    36_017_075_762_092_179,
+    18_012_932_708_689_205,
+    9_007_016_009_513_623,
+    4_503_576_721_087_964,
+    2_251_796_950_380_271,
+    1_125_899_548_928_887,
+    562_949_908_682_076,
+    281_474_971_118_251,
+    140_737_487_656_277,
+    70_368_744_090_283,
+    35_184_372_077_909,
+    17_592_186_043_051,
+    8_796_093_022_037,
+    4_398_046_511_083,
+    2_199_023_255_549,
+];
+
+fn atan_pow2(i: u32) -> i128 {
+    if i < 20 {
+        ATAN_Q60[i as usize]
+    } else {
+        1 << (Q - i)
+    }
+}
+
+fn shift_round(value: i128, shift: i32) -> i128 {
+    if shift <= 0 {
+        value << -shift
+    } else {
+        (value + (1 << (shift - 1))) >> shift
+    }
+}
+
+/// Q4.60 radians.
+fn sin_cos_q60(theta: i128) -> (i128, i128) {
+    let mut z = theta.rem_euclid(2 * PI_Q60);
+    if z > PI_Q60 {
+        z -= 2 * PI_Q60;
+    }
+    // CORDIC converges on [-π/2, π/2]; rotate by π outside of it.
+    let flip = z.abs() > PI_Q60 / 2;
+    if flip {
+        z -= PI_Q60 * z.signum();
+    }
+    let (mut x, mut y) = (CORDIC_K_Q60, 0);
+    for i in 0..=Q {
+        let (dx, dy) = (y >> i, x >> i);
+        if z >= 0 {
+            x -= dx;
+            y += dy;
+            z -= atan_pow2(i);
+        } else {
+            x += dx;
+            y -= dy;
+            z += atan_pow2(i);
+        }
+    }
+    if flip {
+        (-y, -x)
+    } else {
+        (y, x)
+    }
+}
+
+/// Q4.60 radians; `y` and `x` may share any scale.
+fn atan2_q60(y: i128, x: i128) -> i128 {
+    if x == 0 && y == 0 {
+        return 0;
+    }
+    // Normalize so the larger magnitude has about 100 significant bits.
+    let bits = 128 - x.unsigned_abs().max(y.unsigned_abs()).leading_zeros() as i32;
+    let (mut x, mut y) = (shift_round(x, bits - 100), shift_round(y, bits - 100));
+    let mut z = 0;
+    if x < 0 {
+        z = if y >= 0 { PI_Q60 } else { -PI_Q60 };
+        x = -x;
+        y = -y;
+    }
+    for i in 0..=Q {
+        let (dx, dy) = (y >> i, x >> i);
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            z += atan_pow2(i);
+        } else {
+            x -= dx;
+            y += dy;
+            z -= atan_pow2(i);
+        }
+    }
+    z
+}
+
+fn isqrt(value: u128) -> u128 {
+    let mut remainder = value;
+    let mut result = 0u128;
+    let mut bit = 1u128 << 126;
+    while bit > value {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if remainder >= result + bit {
+            remainder -= result + bit;
+            result = (result >> 1) + bit;
+        } else {
+            result >>= 1;
+        }
+        bit >>= 2;
+    }
+    result
+}
+
+macro_rules! impl_fixed {
+    ($($i:ty),*) => {
+        $(
+            impl<const FRAC: u32> Fixed<$i, FRAC> {
+                pub const MIN: Self = Self(<$i>::MIN);
+                pub const MAX: Self = Self(<$i>::MAX);
+
+                pub fn from_bits(bits: $i) -> Self {
+                    const { assert!(FRAC < <$i>::BITS, "Fixed needs a sign bit") }
+                    Self(bits)
+                }
+
+                pub fn to_bits(self) -> $i {
+                    self.0
+                }
+
+                /// Clamps a raw value in `FRAC` format into range.
+                fn saturate(raw: i128) -> Self {
+                    Self::from_bits(raw.clamp(<$i>::MIN as i128, <$i>::MAX as i128) as $i)
+                }
+
+                fn from_q60(value: i128) -> Self {
+                    Self::saturate(shift_round(value, Q as i32 - FRAC as i32))
+                }
+
+                fn to_q60(self) -> i128 {
+                    shift_round(self.0 as i128, FRAC as i32 - Q as i32)
+                }
+
+                pub fn from_int(value: $i) -> Self {
+                    Self::saturate((value as i128) << FRAC)
+                }
+
+                /// Rounds to the nearest representable value.
+                pub fn from_f64(value: f64) -> Self {
+                    Self::saturate((value * (1u128 << FRAC) as f64).round() as i128)
+                }
+
+                pub fn to_f64(self) -> f64 {
+                    self.0 as f64 / (1u128 << FRAC) as f64
+                }
+            }
+
+            impl<const FRAC: u32> One for Fixed<$i, FRAC> {
+                fn one() -> Self {
+                    Self::saturate(1 << FRAC)
+                }
+            }
+
+            impl<const FRAC: u32> Field for Fixed<$i, FRAC> {
+                fn is_negligible(&self) -> bool {
+                    self.0 == 0
+                }
+            }
+
+            impl<const FRAC: u32> Real for Fixed<$i, FRAC> {
+                fn pi() -> Self {
+                    Self::from_q60(PI_Q60)
+                }
+
+                fn abs(self) -> Self {
+                    Self::from_bits(self.0.saturating_abs())
+                }
+
+                /// Rounds down; negative inputs give zero.
+                fn sqrt(self) -> Self {
+                    if self.0 <= 0 {
+                        return Self::default();
+                    }
+                    Self::saturate(isqrt((self.0 as u128) << FRAC) as i128)
+                }
+
+                fn sin(self) -> Self {
+                    Self::from_q60(sin_cos_q60(self.to_q60()).0)
+                }
+
+                fn cos(self) -> Self {
+                    Self::from_q60(sin_cos_q60(self.to_q60()).1)
+                }
+
+                fn atan2(self, x: Self) -> Self {
+                    Self::from_q60(atan2_q60(self.0 as i128, x.0 as i128))
+                }
+
+                fn to_radians(self) -> Self {
+                    Self::saturate(shift_round(self.0 as i128 * PI_OVER_180_Q60, Q as i32))
+                }
+            }
+
+            impl<const FRAC: u32> Add for Fixed<$i, FRAC> {
+                type Output = Self;
+                fn add(self, rhs: Self) -> Self::Output {
+                    Self(self.0.saturating_add(rhs.0))
+                }
+            }
+
+            impl<const FRAC: u32> Sub for Fixed<$i, FRAC> {
+                type Output = Self;
+                fn sub(self, rhs: Self) -> Self::Output {
+                    Self(self.0.saturating_sub(rhs.0))
+                }
+            }
+
+            impl<const FRAC: u32> Mul for Fixed<$i, FRAC> {
+                type Output = Self;
+                fn mul(self, rhs: Self) -> Self::Output {
+                    Self::saturate(shift_round(self.0 as i128 * rhs.0 as i128, FRAC as i32))
+                }
+            }
+
+            impl<const FRAC: u32> Div for Fixed<$i, FRAC> {
+                type Output = Self;
+                fn div(self, rhs: Self) -> Self::Output {
+                    if rhs.0 == 0 {
+                        return if self.0 >= 0 { Self::MAX } else { Self::MIN };
+                    }
+                    Self::saturate(((self.0 as i128) << FRAC) / rhs.0 as i128)
+                }
+            }
+
+            impl<const FRAC: u32> Neg for Fixed<$i, FRAC> {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self(self.0.saturating_neg())
+                }
+            }
+
+            impl<const FRAC: u32> Sum for Fixed<$i, FRAC> {
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(Self::default(), |acc, x| acc + x)
+                }
+            }
+
+            impl<const FRAC: u32> fmt::Debug for Fixed<$i, FRAC> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "Fixed({})", self.to_f64())
+                }
+            }
+
+            impl<const FRAC: u32> fmt::Display for Fixed<$i, FRAC> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.to_f64(), f)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed!(i32, i64);
+
+impl<I: Copy, const FRAC: u32> Conjugate for Fixed<I, FRAC> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        let big = Q16_16::from_int(30000);
+        assert_eq!(big + big, Q16_16::MAX);
+        assert_eq!(-big - big, Q16_16::MIN);
+        assert_eq!(big * big, Q16_16::MAX);
+        assert_eq!(Q16_16::from_int(1) / Q16_16::default(), Q16_16::MAX);
+        assert_eq!(
+            Q16_16::from_f64(1.5) * Q16_16::from_f64(-2.0),
+            Q16_16::from_int(-3)
+        );
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(Q16_16::from_int(9).sqrt(), Q16_16::from_int(3));
+        let root2 = Q32_32::from_int(2).sqrt().to_f64();
+        assert!((root2 - core::f64::consts::SQRT_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cordic() {
+        for &angle in &[0.0, 0.5, -1.2, 2.0, 3.0, -3.1, 7.0] {
+            let a = Q32_32::from_f64(angle);
+            assert!((a.sin().to_f64() - angle.sin()).abs() < 1e-8);
+            assert!((a.cos().to_f64() - angle.cos()).abs() < 1e-8);
+        }
+        let y = Q16_16::from_f64(-1.0);
+        let x = Q16_16::from_f64(-1.0);
+        assert!((y.atan2(x).to_f64() - (-1.0f64).atan2(-1.0)).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,201 @@
+use super::{Conjugate, Field, One};
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A closed interval `[lo, hi]` with bounds rounded outward, so it always
+/// encloses the exact result.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct Interval<T> {
+    lo: T,
+    hi: T,
+}
+
+impl<T: Copy + PartialOrd> Interval<T> {
+    pub fn new(lo: T, hi: T) -> Self {
+        assert!(lo <= hi, "Interval lower bound must not exceed upper bound");
+        Self { lo, hi }
+    }
+
+    pub fn point(value: T) -> Self {
+        Self::new(value, value)
+    }
+
+    pub fn lo(&self) -> T {
+        self.lo
+    }
+
+    pub fn hi(&self) -> T {
+        self.hi
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    pub fn encloses(&self, other: &Self) -> bool {
+        self.lo <= other.lo && other.hi <= self.hi
+    }
+}
+
+macro_rules! impl_interval {
+    ($($t:ty),*) => {
+        $(
+            impl Interval<$t> {
+                pub const ENTIRE: Self = Self {
+                    lo: <$t>::NEG_INFINITY,
+                    hi: <$t>::INFINITY,
+                };
+
+                fn outward(lo: $t, hi: $t) -> Self {
+                    Self {
+                        lo: lo.next_down(),
+                        hi: hi.next_up(),
+                    }
+                }
+
+                pub fn width(&self) -> $t {
+                    (self.hi - self.lo).next_up()
+                }
+
+                pub fn midpoint(&self) -> $t {
+                    self.lo + (self.hi - self.lo) / 2.0
+                }
+
+                pub fn sqrt(&self) -> Self {
+                    assert!(self.lo >= 0.0, "Interval square root of a negative value");
+                    Self::outward(self.lo.sqrt(), self.hi.sqrt()).clamp_lo(0.0)
+                }
+
+                fn clamp_lo(self, min: $t) -> Self {
+                    Self {
+                        lo: self.lo.max(min),
+                        hi: self.hi,
+                    }
+                }
+            }
+
+            impl One for Interval<$t> {
+                fn one() -> Self {
+                    Self::point(1.0)
+                }
+            }
+
+            impl Field for Interval<$t> {
+                fn is_negligible(&self) -> bool {
+                    self.contains(0.0)
+                }
+
+                fn unresolved_determinant() -> Self {
+                    Self::ENTIRE
+                }
+            }
+
+            impl Add for Interval<$t> {
+                type Output = Self;
+                fn add(self, rhs: Self) -> Self::Output {
+                    Self::outward(self.lo + rhs.lo, self.hi + rhs.hi)
+                }
+            }
+
+            impl Sub for Interval<$t> {
+                type Output = Self;
+                fn sub(self, rhs: Self) -> Self::Output {
+                    Self::outward(self.lo - rhs.hi, self.hi - rhs.lo)
+                }
+            }
+
+            impl Mul for Interval<$t> {
+                type Output = Self;
+                fn mul(self, rhs: Self) -> Self::Output {
+                    let products = [
+                        self.lo * rhs.lo,
+                        self.lo * rhs.hi,
+                        self.hi * rhs.lo,
+                        self.hi * rhs.hi,
+                    ];
+                    let lo = products.iter().copied().fold(<$t>::INFINITY, <$t>::min);
+                    let hi = products.iter().copied().fold(<$t>::NEG_INFINITY, <$t>::max);
+                    Self::outward(lo, hi)
+                }
+            }
+
+            impl Div for Interval<$t> {
+                type Output = Self;
+                /// `ENTIRE` when `rhs` contains zero.
+                fn div(self, rhs: Self) -> Self::Output {
+                    if rhs.contains(0.0) {
+                        return Self::ENTIRE;
+                    }
+                    let quotients = [
+                        self.lo / rhs.lo,
+                        self.lo / rhs.hi,
+                        self.hi / rhs.lo,
+                        self.hi / rhs.hi,
+                    ];
+                    let lo = quotients.iter().copied().fold(<$t>::INFINITY, <$t>::min);
+                    let hi = quotients.iter().copied().fold(<$t>::NEG_INFINITY, <$t>::max);
+                    Self::outward(lo, hi)
+                }
+            }
+
+            impl Sum for Interval<$t> {
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(Self::default(), |acc, x| acc + x)
+                }
+            }
+        )*
+    };
+}
+
+impl_interval!(f32, f64);
+
+impl<T: Copy + Neg<Output = T>> Neg for Interval<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl<T: Copy> Conjugate for Interval<T> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:?}, {:?}]", self.lo, self.hi)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outward_rounding() {
+        let tenth = Interval::point(1.0_f64) / Interval::point(10.0);
+        assert!(tenth.lo() < 0.1 && 0.1 < tenth.hi());
+        let sum = tenth + tenth + tenth;
+        assert!(sum.contains(0.30000000000000004) && sum.lo() < 0.3);
+    }
+
+    #[test]
+    fn test_mul_signs() {
+        let a = Interval::new(-2.0_f64, 3.0);
+        let b = Interval::new(-1.0, 4.0);
+        let product = a * b;
+        assert!(product.encloses(&Interval::new(-8.0, 12.0)));
+        assert!(product.width() < 20.0 + 1e-12);
+    }
+}
@@ -0,0 +1,231 @@
+pub mod angle;
+pub mod complex;
+pub mod dual;
+pub mod fixed;
+pub mod interval;
+pub mod modp;
+pub mod quaternion;
+pub mod rational;
+pub mod semiring;
+
+pub use angle::{Angle, Deg, Rad};
+pub use complex::Complex;
+pub use dual::{Dual, DualN};
+pub use fixed::Fixed;
+pub use interval::Interval;
+pub use modp::ModP;
+pub use quaternion::Quaternion;
+pub use rational::Rational;
+pub use semiring::{BoolOr, MaxPlus, MinPlus, Semiring};
+
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+pub trait One {
+    fn one() -> Self;
+}
+
+macro_rules! impl_one {
+    ($($t:ty => $one:expr),*) => {
+        $(
+            impl One for $t {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+impl_one!(
+    i8 => 1, i16 => 1, i32 => 1, i64 => 1, i128 => 1, isize => 1,
+    u8 => 1, u16 => 1, u32 => 1, u64 => 1, u128 => 1, usize => 1,
+    f32 => 1.0, f64 => 1.0
+);
+
+/// Complex conjugation; the identity for real scalars.
+pub trait Conjugate {
+    fn conjugate(&self) -> Self;
+}
+
+macro_rules! impl_real_conjugate {
+    ($($t:ty),*) => {
+        $(
+            impl Conjugate for $t {
+                fn conjugate(&self) -> Self {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_real_conjugate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Scalars forming a ring, with `Default` as the additive identity.
+pub trait Ring:
+    Copy
+    + Default
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Sum
+    + One
+{
+}
+
+impl<T> Ring for T where
+    T: Copy + Default + PartialEq + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Sum + One
+{
+}
+
+/// Signed integers with overflow-checked arithmetic.
+pub trait Integer: Ring {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+                fn checked_div(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_div(self, rhs)
+                }
+                fn checked_neg(self) -> Option<Self> {
+                    <$t>::checked_neg(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize);
+
+pub trait Field: Ring + Neg<Output = Self> + Div<Output = Self> {
+    /// Whether the value is too close to zero to be used as a pivot.
+    fn is_negligible(&self) -> bool;
+
+    /// What `determinant` returns when a column has no usable pivot.
+    fn unresolved_determinant() -> Self {
+        Self::default()
+    }
+}
+
+impl Field for f64 {
+    fn is_negligible(&self) -> bool {
+        self.abs() <= 1e-9
+    }
+}
+
+impl Field for f32 {
+    fn is_negligible(&self) -> bool {
+        self.abs() <= 1e-6
+    }
+}
+
+pub trait Real: Field + PartialOrd + Conjugate {
+    fn pi() -> Self;
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn atan2(self, x: Self) -> Self;
+    fn to_radians(self) -> Self;
+}
+
+macro_rules! impl_real_float {
+    ($($t:ident),*) => {
+        $(
+            impl Real for $t {
+                fn pi() -> Self {
+                    core::$t::consts::PI
+                }
+                fn abs(self) -> Self {
+                    $t::abs(self)
+                }
+                fn sqrt(self) -> Self {
+                    $t::sqrt(self)
+                }
+                fn sin(self) -> Self {
+                    $t::sin(self)
+                }
+                fn cos(self) -> Self {
+                    $t::cos(self)
+                }
+                fn atan2(self, x: Self) -> Self {
+                    $t::atan2(self, x)
+                }
+                fn to_radians(self) -> Self {
+                    $t::to_radians(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_real_float!(f32, f64);
+
+/// `Real` scalars measure length in their own type, integers in `f64`.
+pub trait EuclideanNorm: Copy {
+    type Output;
+    fn euclidean_norm(components: &[Self]) -> Self::Output;
+}
+
+impl<T: Real> EuclideanNorm for T {
+    type Output = T;
+    fn euclidean_norm(components: &[Self]) -> T {
+        components
+            .iter()
+            .fold(T::default(), |acc, &c| acc + c * c)
+            .sqrt()
+    }
+}
+
+macro_rules! impl_integer_norm {
+    ($($t:ty),*) => {
+        $(
+            impl EuclideanNorm for $t {
+                type Output = f64;
+                fn euclidean_norm(components: &[Self]) -> f64 {
+                    components
+                        .iter()
+                        .fold(0.0, |acc, &c| acc + f64::from(c) * f64::from(c))
+                        .sqrt()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_norm!(i8, i16, i32, u8, u16, u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one() {
+        assert_eq!(<i64 as One>::one(), 1);
+        assert_eq!(<f64 as One>::one(), 1.0);
+    }
+
+    #[test]
+    fn test_is_negligible() {
+        assert!(1e-12_f64.is_negligible());
+        assert!(!0.5_f64.is_negligible());
+    }
+}
@@ -0,0 +1,237 @@
+use super::{Conjugate, Field, One};
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An integer modulo `P`. `P` must be prime, which is checked at compile time:
+///
+/// ```compile_fail
+/// let x = matops::scalar::ModP::<4>::new(2);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModP<const P: u64>(u64);
+
+impl<const P: u64> ModP<P> {
+    const PRIME: () = assert!(is_prime(P), "ModP modulus must be prime");
+
+    pub fn new(value: u64) -> Self {
+        let () = Self::PRIME;
+        Self(value % P)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut result = Self::new(1);
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    pub fn inverse(self) -> Option<Self> {
+        let (mut old_r, mut r) = (self.0 as i128, P as i128);
+        let (mut old_s, mut s) = (1i128, 0i128);
+        while r != 0 {
+            let q = old_r / r;
+            (old_r, r) = (r, old_r - q * r);
+            (old_s, s) = (s, old_s - q * s);
+        }
+        if old_r != 1 {
+            return None;
+        }
+        Some(Self(old_s.rem_euclid(P as i128) as u64))
+    }
+}
+
+/// Deterministic Miller–Rabin; these bases are enough for every `u64`.
+const fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let bases = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    let mut i = 0;
+    while i < bases.len() {
+        if n == bases[i] {
+            return true;
+        }
+        if n % bases[i] == 0 {
+            return false;
+        }
+        i += 1;
+    }
+    let mut d = n - 1;
+    let mut r = 0;
+    while d % 2 == 0 {
+        d /= 2;
+        r += 1;
+    }
+    let mut i = 0;
+    'bases: while i < bases.len() {
+        let mut x = pow_mod(bases[i], d, n);
+        i += 1;
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        let mut k = 1;
+        while k < r {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+            k += 1;
+        }
+        return false;
+    }
+    true
+}
+
+const fn mul_mod(a: u64, b: u64, n: u64) -> u64 {
+    ((a as u128 * b as u128) % n as u128) as u64
+}
+
+const fn pow_mod(mut base: u64, mut exp: u64, n: u64) -> u64 {
+    let mut result = 1;
+    base %= n;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, n);
+        }
+        base = mul_mod(base, base, n);
+        exp >>= 1;
+    }
+    result
+}
+
+impl<const P: u64> Default for ModP<P> {
+    fn default() -> Self {
+        let () = Self::PRIME;
+        Self(0)
+    }
+}
+
+impl<const P: u64> From<i64> for ModP<P> {
+    fn from(value: i64) -> Self {
+        let () = Self::PRIME;
+        Self((value as i128).rem_euclid(P as i128) as u64)
+    }
+}
+
+impl<const P: u64> One for ModP<P> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const P: u64> Conjugate for ModP<P> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<const P: u64> Field for ModP<P> {
+    fn is_negligible(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> Add for ModP<P> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for ModP<P> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(((self.0 as u128 + P as u128 - rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Mul for ModP<P> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Div for ModP<P> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs
+            .inverse()
+            .expect("ModP division by a non-invertible element")
+    }
+}
+
+impl<const P: u64> Neg for ModP<P> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::default() - self
+    }
+}
+
+impl<const P: u64> Sum for ModP<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
+
+impl<const P: u64> fmt::Debug for ModP<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.0, P)
+    }
+}
+
+impl<const P: u64> fmt::Display for ModP<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = ModP::<7>::new(5);
+        let b = ModP::<7>::new(4);
+        assert_eq!((a + b).value(), 2);
+        assert_eq!((b - a).value(), 6);
+        assert_eq!((a * b).value(), 6);
+        assert_eq!((a / b) * b, a);
+    }
+
+    #[test]
+    fn test_is_prime() {
+        let primes: Vec<u64> = (0..50).filter(|&n| is_prime(n)).collect();
+        assert_eq!(
+            primes,
+            [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]
+        );
+        assert!(is_prime(18_446_744_073_709_551_557));
+        // Strong pseudoprimes to small bases, and a square of a large prime.
+        assert!(!is_prime(3_215_031_751));
+        assert!(!is_prime(3_825_123_056_546_413_051));
+        assert!(!is_prime(4_294_967_291 * 4_294_967_291));
+    }
+
+    #[test]
+    fn test_inverse() {
+        assert_eq!(ModP::<13>::new(0).inverse(), None);
+        for x in 1..13 {
+            let x = ModP::<13>::new(x);
+            assert_eq!(x * x.inverse().unwrap(), ModP::new(1));
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use super::{Angle, Conjugate, Field, One, Real};
+use crate::matrix::Matrix;
+use crate::vector::geometry::ZeroLengthError;
+use crate::vector::Vector;
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// A quaternion `w + xi + yj + zk`.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct Quaternion<T> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Quaternion<T> {
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self { w, x, y, z }
+    }
+}
+
+impl<T: Copy> Quaternion<T> {
+    pub fn from_parts(w: T, v: Vector<T, 3>) -> Self {
+        let [x, y, z] = v.data;
+        Self::new(w, x, y, z)
+    }
+
+    /// The imaginary part `(x, y, z)`.
+    pub fn vector(&self) -> Vector<T, 3> {
+        Vector::new([self.x, self.y, self.z])
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T>> Quaternion<T> {
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn norm_sqr(&self) -> T {
+        self.dot(self)
+    }
+
+    fn scale(&self, k: T) -> Self {
+        Self::new(self.w * k, self.x * k, self.y * k, self.z * k)
+    }
+}
+
+impl<T: Field> Quaternion<T> {
+    pub fn inverse(&self) -> Option<Self> {
+        let n = self.norm_sqr();
+        if n.is_negligible() {
+            return None;
+        }
+        Some(self.conjugate().scale(T::one() / n))
+    }
+}
+
+impl<T: Real> Quaternion<T> {
+    pub fn norm(&self) -> T {
+        self.norm_sqr().sqrt()
+    }
+
+    pub fn normalize(&self) -> Result<Self, ZeroLengthError> {
+        let n = self.norm();
+        if n == T::default() {
+            return Err(ZeroLengthError);
+        }
+        Ok(self.scale(T::one() / n))
+    }
+
+    /// Rotates `v` by this unit quaternion.
+    pub fn rotate(&self, v: &Vector<T, 3>) -> Vector<T, 3> {
+        let u = self.vector();
+        let two = T::one() + T::one();
+        let t = u.cross(v) * two;
+        *v + t * self.w + u.cross(&t)
+    }
+
+    pub fn from_axis_angle(
+        axis: &Vector<T, 3>,
+        angle: impl Into<Angle<T>>,
+    ) -> Result<Self, ZeroLengthError> {
+        let n = axis.normalize()?;
+        let (sin, cos) = (angle.into() / (T::one() + T::one())).sin_cos();
+        Ok(Self::from_parts(cos, n * sin))
+    }
+
+    /// Angle in `[0, π]`; the axis is `x` for the identity.
+    pub fn to_axis_angle(&self) -> (Vector<T, 3>, Angle<T>) {
+        let q = if self.w < T::default() { -*self } else { *self };
+        let v = q.vector();
+        let s = v.magnitude();
+        let two = T::one() + T::one();
+        if s == T::default() {
+            let zero = T::default();
+            return (Vector::new([T::one(), zero, zero]), Angle::default());
+        }
+        (v * (T::one() / s), Angle::atan2(s, q.w) * two)
+    }
+
+    pub fn to_rotation_matrix(&self) -> Matrix<T, 3, 3> {
+        let Self { w, x, y, z } = *self;
+        let one = T::one();
+        let two = one + one;
+        Matrix::new([
+            [
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        ])
+    }
+
+    pub fn from_rotation_matrix(m: &Matrix<T, 3, 3>) -> Self {
+        let d = &m.data;
+        let one = T::one();
+        let two = one + one;
+        let four = two + two;
+        let trace = d[0][0] + d[1][1] + d[2][2];
+        if trace > T::default() {
+            let s = (trace + one).sqrt() * two;
+            Self::new(
+                s / four,
+                (d[2][1] - d[1][2]) / s,
+                (d[0][2] - d[2][0]) / s,
+                (d[1][0] - d[0][1]) / s,
+            )
+        } else if d[0][0] > d[1][1] && d[0][0] > d[2][2] {
+            let s = (one + d[0][0] - d[1][1] - d[2][2]).sqrt() * two;
+            Self::new(
+                (d[2][1] - d[1][2]) / s,
+                s / four,
+                (d[0][1] + d[1][0]) / s,
+                (d[0][2] + d[2][0]) / s,
+            )
+        } else if d[1][1] > d[2][2] {
+            let s = (one + d[1][1] - d[0][0] - d[2][2]).sqrt() * two;
+            Self::new(
+                (d[0][2] - d[2][0]) / s,
+                (d[0][1] + d[1][0]) / s,
+                s / four,
+                (d[1][2] + d[2][1]) / s,
+            )
+        } else {
+            let s = (one + d[2][2] - d[0][0] - d[1][1]).sqrt() * two;
+            Self::new(
+                (d[1][0] - d[0][1]) / s,
+                (d[0][2] + d[2][0]) / s,
+                (d[1][2] + d[2][1]) / s,
+                s / four,
+            )
+        }
+    }
+
+    /// Shortest-arc rotation; opposite vectors turn about any perpendicular axis.
+    pub fn from_two_vectors(
+        from: &Vector<T, 3>,
+        to: &Vector<T, 3>,
+    ) -> Result<Self, ZeroLengthError> {
+        let scale = from.magnitude() * to.magnitude();
+        if scale == T::default() {
+            return Err(ZeroLengthError);
+        }
+        let w = scale + from.dot(to);
+        if (w / scale).is_negligible() {
+            let [x, y, z] = from.data;
+            let zero = T::default();
+            let axis = if x.abs() > z.abs() {
+                Vector::new([-y, x, zero])
+            } else {
+                Vector::new([zero, -z, y])
+            };
+            return Self::from_parts(zero, axis).normalize();
+        }
+        Self::from_parts(w, from.cross(to)).normalize()
+    }
+
+    /// `exp((0, v))`, rotating by `2|v|` about `v`.
+    pub fn exp(v: &Vector<T, 3>) -> Self {
+        let theta = v.magnitude();
+        if theta == T::default() {
+            return Self::one();
+        }
+        Self::from_parts(theta.cos(), *v * (theta.sin() / theta))
+    }
+
+    /// The inverse of `exp` for angles below π.
+    pub fn log(&self) -> Vector<T, 3> {
+        let v = self.vector();
+        let s = v.magnitude();
+        if s == T::default() {
+            return v;
+        }
+        v * (s.atan2(self.w) / s)
+    }
+
+    /// Along the shorter arc.
+    pub fn nlerp(&self, other: &Self, t: T) -> Self {
+        let other = if self.dot(other) < T::default() {
+            -*other
+        } else {
+            *other
+        };
+        let q = self.scale(T::one() - t) + other.scale(t);
+        q.normalize().unwrap_or(*self)
+    }
+
+    /// Along the shorter arc, at constant angular velocity.
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let other = if self.dot(other) < T::default() {
+            -*other
+        } else {
+            *other
+        };
+        let two = T::one() + T::one();
+        let theta = (*self - other).norm().atan2((*self + other).norm()) * two;
+        let sin = theta.sin();
+        if sin.is_negligible() {
+            return self.nlerp(&other, t);
+        }
+        self.scale(((T::one() - t) * theta).sin() / sin) + other.scale((t * theta).sin() / sin)
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Conjugate for Quaternion<T> {
+    fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: One + Default> One for Quaternion<T> {
+    fn one() -> Self {
+        Self::new(T::one(), T::default(), T::default(), T::default())
+    }
+}
+
+impl<T: Add<Output = T>> Add for Quaternion<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w + rhs.w,
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+        )
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Quaternion<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w - rhs.w,
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+        )
+    }
+}
+
+/// The Hamilton product.
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Mul for Quaternion<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let Self {
+            w: a1,
+            x: b1,
+            y: c1,
+            z: d1,
+        } = self;
+        let Self {
+            w: a2,
+            x: b2,
+            y: c2,
+            z: d2,
+        } = rhs;
+        Self::new(
+            a1 * a2 - b1 * b2 - c1 * c2 - d1 * d2,
+            a1 * b2 + b1 * a2 + c1 * d2 - d1 * c2,
+            a1 * c2 - b1 * d2 + c1 * a2 + d1 * b2,
+            a1 * d2 + b1 * c2 - c1 * b2 + d1 * a2,
+        )
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Quaternion<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Add<Output = T> + Default> Sum for Quaternion<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, x| acc + x)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Quaternion<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}i, {}j, {}k)", self.w, self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hamilton_product() {
+        let i = Quaternion::new(0, 1, 0, 0);
+        let j = Quaternion::new(0, 0, 1, 0);
+        let k = Quaternion::new(0, 0, 0, 1);
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+        assert_eq!(i * i, -Quaternion::one());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let q = Quaternion::new(1.0, 1.0, -1.0, 1.0);
+        assert_eq!(q * q.inverse().unwrap(), Quaternion::one());
+        assert_eq!(Quaternion::new(0.0, 0.0, 0.0, 0.0).inverse(), None);
+    }
+}
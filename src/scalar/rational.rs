@@ -0,0 +1,398 @@
+use super::{Conjugate, Field, One};
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An `i128` fraction in lowest terms. The operators panic on overflow; the
+/// `checked_*` methods return `None`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+fn gcd(a: i128, b: i128) -> u128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+impl Rational {
+    pub const ZERO: Self = Self { num: 0, den: 1 };
+    pub const ONE: Self = Self { num: 1, den: 1 };
+
+    /// Panics if `den` is zero or the reduced fraction does not fit.
+    pub fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "Rational denominator must not be zero");
+        Self::checked_new(num, den).expect("Rational overflow")
+    }
+
+    pub fn checked_new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        if num == 0 {
+            return Some(Self::ZERO);
+        }
+        let g = gcd(num, den);
+        // With `num` nonzero, `g` only exceeds `i128::MAX` when both are
+        // `i128::MIN`.
+        let (mut num, mut den) = if g > i128::MAX as u128 {
+            (1, 1)
+        } else {
+            (num / g as i128, den / g as i128)
+        };
+        if den < 0 {
+            num = num.checked_neg()?;
+            den = den.checked_neg()?;
+        }
+        Some(Self { num, den })
+    }
+
+    pub fn numer(&self) -> i128 {
+        self.num
+    }
+
+    pub fn denom(&self) -> i128 {
+        self.den
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let g = gcd(self.den, rhs.den) as i128;
+        let num = self
+            .num
+            .checked_mul(rhs.den / g)?
+            .checked_add(rhs.num.checked_mul(self.den / g)?)?;
+        Self::checked_new(num, self.den.checked_mul(rhs.den / g)?)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let g1 = gcd(self.num, rhs.den) as i128;
+        let g2 = gcd(rhs.num, self.den) as i128;
+        let num = (self.num / g1).checked_mul(rhs.num / g2)?;
+        let den = (self.den / g2).checked_mul(rhs.den / g1)?;
+        Self::checked_new(num, den)
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.checked_recip()?)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+
+    pub fn checked_recip(self) -> Option<Self> {
+        if self.num == 0 {
+            return None;
+        }
+        Self::checked_new(self.den, self.num)
+    }
+
+    pub fn recip(self) -> Self {
+        assert!(self.num != 0, "Rational division by zero");
+        self.checked_recip().expect("Rational overflow")
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl One for Rational {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl Conjugate for Rational {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl Field for Rational {
+    fn is_negligible(&self) -> bool {
+        self.num == 0
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Rational {
+                fn from(value: $t) -> Self {
+                    Self { num: value as i128, den: 1 }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl Add for Rational {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("Rational overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("Rational overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("Rational overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs.recip()).expect("Rational overflow")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("Rational overflow")
+    }
+}
+
+impl Sum for Rational {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compare continued fraction expansions so no product can overflow.
+        let (mut a, mut b, mut c, mut d) = (self.num, self.den, other.num, other.den);
+        let mut flipped = false;
+        loop {
+            let (q1, q2) = (a.div_euclid(b), c.div_euclid(d));
+            if q1 != q2 {
+                let ord = q1.cmp(&q2);
+                return if flipped { ord.reverse() } else { ord };
+            }
+            let (r1, r2) = (a.rem_euclid(b), c.rem_euclid(d));
+            let ord = match (r1 == 0, r2 == 0) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => {
+                    // a/b - q = r1/b, so compare b/r1 and d/r2 the other way round.
+                    a = b;
+                    b = r1;
+                    c = d;
+                    d = r2;
+                    flipped = !flipped;
+                    continue;
+                }
+            };
+            return if flipped { ord.reverse() } else { ord };
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Debug for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+#[cfg(feature = "bigint")]
+pub use self::big::BigRational;
+
+#[cfg(feature = "bigint")]
+mod big {
+    use super::Rational;
+    use crate::matrix::Matrix;
+    use crate::vector::Vector;
+    use core::array;
+    use num_bigint::BigInt;
+    use num_rational::Ratio;
+    use num_traits::{One, Zero};
+
+    /// Not `Copy`, so it is only reachable through the `_big` methods.
+    pub type BigRational = Ratio<BigInt>;
+
+    impl From<Rational> for BigRational {
+        fn from(value: Rational) -> Self {
+            Ratio::new(BigInt::from(value.num), BigInt::from(value.den))
+        }
+    }
+
+    fn to_big<const M: usize, const N: usize>(m: &Matrix<Rational, M, N>) -> [[BigRational; N]; M] {
+        array::from_fn(|i| array::from_fn(|j| BigRational::from(m.data[i][j])))
+    }
+
+    /// Returns the rank of `left`.
+    fn gauss_jordan_big<const M: usize, const N: usize, const K: usize>(
+        left: &mut [[BigRational; N]; M],
+        right: &mut [[BigRational; K]; M],
+    ) -> usize {
+        let mut row = 0;
+        for col in 0..N {
+            if row == M {
+                break;
+            }
+            let pivot = match (row..M).find(|&j| !left[j][col].is_zero()) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            left.swap(row, pivot);
+            right.swap(row, pivot);
+            let factor = left[row][col].recip();
+            left[row].iter_mut().for_each(|x| *x *= &factor);
+            right[row].iter_mut().for_each(|x| *x *= &factor);
+            let (pivot_left, pivot_right) = (left[row].clone(), right[row].clone());
+            for j in 0..M {
+                if j != row && !left[j][col].is_zero() {
+                    let factor = left[j][col].clone();
+                    for (x, p) in left[j].iter_mut().zip(&pivot_left) {
+                        *x -= &factor * p;
+                    }
+                    for (x, p) in right[j].iter_mut().zip(&pivot_right) {
+                        *x -= &factor * p;
+                    }
+                }
+            }
+            row += 1;
+        }
+        row
+    }
+
+    impl<const M: usize, const N: usize> Matrix<Rational, M, N> {
+        pub fn rref_big(&self) -> [[BigRational; N]; M] {
+            let mut result = to_big(self);
+            let mut none: [[BigRational; 0]; M] = array::from_fn(|_| []);
+            gauss_jordan_big(&mut result, &mut none);
+            result
+        }
+    }
+
+    impl<const M: usize> Matrix<Rational, M, M> {
+        pub fn determinant_big(&self) -> BigRational {
+            let mut mat = to_big(self);
+            let mut det = BigRational::one();
+            for i in 0..M {
+                let pivot = match (i..M).find(|&j| !mat[j][i].is_zero()) {
+                    Some(pivot) => pivot,
+                    None => return BigRational::zero(),
+                };
+                if pivot != i {
+                    mat.swap(i, pivot);
+                    det = -det;
+                }
+                let pivot_row = mat[i].clone();
+                for row in mat[i + 1..].iter_mut() {
+                    let factor = &row[i] / &pivot_row[i];
+                    for (x, p) in row[i..].iter_mut().zip(&pivot_row[i..]) {
+                        *x -= &factor * p;
+                    }
+                }
+                det *= &mat[i][i];
+            }
+            det
+        }
+
+        pub fn inverse_big(&self) -> Option<[[BigRational; M]; M]> {
+            let mut mat = to_big(self);
+            let mut inv: [[BigRational; M]; M] = array::from_fn(|i| {
+                array::from_fn(|j| {
+                    if i == j {
+                        BigRational::one()
+                    } else {
+                        BigRational::zero()
+                    }
+                })
+            });
+            if gauss_jordan_big(&mut mat, &mut inv) < M {
+                return None;
+            }
+            Some(inv)
+        }
+
+        pub fn solve_big(&self, b: &Vector<Rational, M>) -> Option<[BigRational; M]> {
+            let mut mat = to_big(self);
+            let mut rhs: [[BigRational; 1]; M] = array::from_fn(|i| [b.data[i].into()]);
+            if gauss_jordan_big(&mut mat, &mut rhs) < M {
+                return None;
+            }
+            Some(rhs.map(|[x]| x))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalization() {
+        let r = Rational::new(6, -8);
+        assert_eq!((r.numer(), r.denom()), (-3, 4));
+        assert_eq!(r, Rational::new(-3, 4));
+        assert_eq!(Rational::checked_new(0, i128::MIN), Some(Rational::ZERO));
+        assert_eq!(Rational::checked_new(0, -5), Some(Rational::ZERO));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Rational::new(1, 3);
+        let b = Rational::new(1, 6);
+        assert_eq!(a + b, Rational::new(1, 2));
+        assert_eq!(a / b, Rational::from(2));
+        assert!(b < a);
+        assert!(Rational::new(i128::MAX, 1)
+            .checked_add(Rational::ONE)
+            .is_none());
+    }
+}
@@ -0,0 +1,257 @@
+use super::One;
+use core::iter::Sum;
+use core::ops::{Add, Mul};
+
+/// `times` distributes over `plus`; `zero` and `one` are their identities.
+pub trait Semiring: Copy {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn plus(self, other: Self) -> Self;
+    fn times(self, other: Self) -> Self;
+}
+
+macro_rules! impl_arithmetic_semiring {
+    ($($t:ty => $zero:expr, $one:expr),*) => {
+        $(
+            impl Semiring for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+                fn one() -> Self {
+                    $one
+                }
+                fn plus(self, other: Self) -> Self {
+                    self + other
+                }
+                fn times(self, other: Self) -> Self {
+                    self * other
+                }
+            }
+        )*
+    };
+}
+
+impl_arithmetic_semiring!(
+    i8 => 0, 1, i16 => 0, 1, i32 => 0, 1, i64 => 0, 1, i128 => 0, 1, isize => 0, 1,
+    u8 => 0, 1, u16 => 0, 1, u32 => 0, 1, u64 => 0, 1, u128 => 0, 1, usize => 0, 1,
+    f32 => 0.0, 1.0, f64 => 0.0, 1.0
+);
+
+/// `+` is `min` and `*` is saturating addition; the zero is the largest value.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MinPlus<T>(pub T);
+
+/// `+` is `max` and `*` is addition; the zero is the smallest value, or `None`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MaxPlus<T>(pub T);
+
+/// `+` is OR and `*` is AND.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct BoolOr(pub bool);
+
+macro_rules! impl_min_plus {
+    ($($t:ty => $inf:expr, $add:ident),*) => {
+        $(
+            impl Semiring for MinPlus<$t> {
+                fn zero() -> Self {
+                    MinPlus($inf)
+                }
+                fn one() -> Self {
+                    MinPlus(0 as $t)
+                }
+                fn plus(self, other: Self) -> Self {
+                    if other.0 < self.0 { other } else { self }
+                }
+                fn times(self, other: Self) -> Self {
+                    if self.0 == $inf || other.0 == $inf {
+                        return Self::zero();
+                    }
+                    MinPlus(self.0.$add(other.0))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_max_plus {
+    ($($t:ty => $neg_inf:expr, $add:ident),*) => {
+        $(
+            impl Semiring for MaxPlus<$t> {
+                fn zero() -> Self {
+                    MaxPlus($neg_inf)
+                }
+                fn one() -> Self {
+                    MaxPlus(0 as $t)
+                }
+                fn plus(self, other: Self) -> Self {
+                    if other.0 > self.0 { other } else { self }
+                }
+                fn times(self, other: Self) -> Self {
+                    if self.0 == $neg_inf || other.0 == $neg_inf {
+                        return Self::zero();
+                    }
+                    MaxPlus(self.0.$add(other.0))
+                }
+            }
+        )*
+    };
+}
+
+impl_min_plus!(
+    i32 => i32::MAX, saturating_add,
+    i64 => i64::MAX, saturating_add,
+    u32 => u32::MAX, saturating_add,
+    u64 => u64::MAX, saturating_add,
+    f32 => f32::INFINITY, add,
+    f64 => f64::INFINITY, add
+);
+
+impl_max_plus!(
+    i32 => i32::MIN, saturating_add,
+    i64 => i64::MIN, saturating_add,
+    f32 => f32::NEG_INFINITY, add,
+    f64 => f64::NEG_INFINITY, add
+);
+
+// The smallest unsigned value is 0, which is already the multiplicative
+// identity, so "no edge" is `None` instead.
+macro_rules! impl_max_plus_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Semiring for MaxPlus<Option<$t>> {
+                fn zero() -> Self {
+                    MaxPlus(None)
+                }
+                fn one() -> Self {
+                    MaxPlus(Some(0))
+                }
+                fn plus(self, other: Self) -> Self {
+                    MaxPlus(self.0.max(other.0))
+                }
+                fn times(self, other: Self) -> Self {
+                    MaxPlus(self.0.zip(other.0).map(|(a, b)| a.saturating_add(b)))
+                }
+            }
+        )*
+    };
+}
+
+impl_max_plus_unsigned!(u32, u64);
+
+impl Semiring for BoolOr {
+    fn zero() -> Self {
+        BoolOr(false)
+    }
+    fn one() -> Self {
+        BoolOr(true)
+    }
+    fn plus(self, other: Self) -> Self {
+        BoolOr(self.0 || other.0)
+    }
+    fn times(self, other: Self) -> Self {
+        BoolOr(self.0 && other.0)
+    }
+}
+
+// The wrappers expose their semiring through the ordinary operator traits so
+// that `Matrix` multiplication, `pow` and `identity` use it directly.
+macro_rules! impl_semiring_ops {
+    ($($w:ident),*) => {
+        $(
+            impl<T> Default for $w<T>
+            where
+                $w<T>: Semiring,
+            {
+                fn default() -> Self {
+                    <Self as Semiring>::zero()
+                }
+            }
+
+            impl<T> One for $w<T>
+            where
+                $w<T>: Semiring,
+            {
+                fn one() -> Self {
+                    <Self as Semiring>::one()
+                }
+            }
+
+            impl<T> Add for $w<T>
+            where
+                $w<T>: Semiring,
+            {
+                type Output = Self;
+                fn add(self, rhs: Self) -> Self::Output {
+                    self.plus(rhs)
+                }
+            }
+
+            impl<T> Mul for $w<T>
+            where
+                $w<T>: Semiring,
+            {
+                type Output = Self;
+                fn mul(self, rhs: Self) -> Self::Output {
+                    self.times(rhs)
+                }
+            }
+
+            impl<T> Sum for $w<T>
+            where
+                $w<T>: Semiring,
+            {
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(<Self as Semiring>::zero(), Semiring::plus)
+                }
+            }
+        )*
+    };
+}
+
+impl_semiring_ops!(MinPlus, MaxPlus);
+
+impl One for BoolOr {
+    fn one() -> Self {
+        BoolOr(true)
+    }
+}
+
+impl Add for BoolOr {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.plus(rhs)
+    }
+}
+
+impl Mul for BoolOr {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.times(rhs)
+    }
+}
+
+impl Sum for BoolOr {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(BoolOr(false), Semiring::plus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_plus() {
+        let a = MinPlus(3);
+        let b = MinPlus(5);
+        assert_eq!(a + b, MinPlus(3));
+        assert_eq!(a * b, MinPlus(8));
+        assert_eq!(a * MinPlus::default(), MinPlus(i32::MAX));
+    }
+
+    #[test]
+    fn test_bool_or() {
+        assert_eq!(BoolOr(true) + BoolOr(false), BoolOr(true));
+        assert_eq!(BoolOr(true) * BoolOr(false), BoolOr(false));
+    }
+}
@@ -0,0 +1,226 @@
+use crate::matrix::Matrix;
+use crate::scalar::Field;
+use core::array;
+use core::cell::RefCell;
+use core::marker::PhantomData;
+
+/// Inputs are node indices; values are row-major.
+#[derive(Clone, Debug)]
+pub(crate) enum Op<T> {
+    Leaf,
+    Add(usize, usize),
+    Sub(usize, usize),
+    Neg(usize),
+    Scale(usize, T),
+    Hadamard(usize, usize),
+    MatMul(usize, usize),
+    Transpose(usize),
+    Sum(usize),
+    Inverse(usize),
+    /// Carries the cofactor matrix.
+    Determinant(usize, Vec<T>),
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Node<T> {
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+    pub(crate) value: Vec<T>,
+    pub(crate) op: Op<T>,
+}
+
+/// Records operations on `Var`s for reverse-mode gradients.
+#[derive(Debug, Default)]
+pub struct Tape<T> {
+    pub(crate) nodes: RefCell<Vec<Node<T>>>,
+}
+
+/// A matrix-valued variable recorded on a `Tape`.
+#[derive(Debug)]
+pub struct Var<'t, T, const M: usize, const N: usize> {
+    pub(crate) tape: &'t Tape<T>,
+    pub(crate) index: usize,
+    pub(crate) shape: PhantomData<[[T; N]; M]>,
+}
+
+impl<T, const M: usize, const N: usize> Clone for Var<'_, T, M, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const M: usize, const N: usize> Copy for Var<'_, T, M, N> {}
+
+#[derive(Debug)]
+pub struct Gradients<'t, T> {
+    tape: &'t Tape<T>,
+    adjoints: Vec<Vec<T>>,
+}
+
+pub(crate) fn to_vec<T: Copy, const M: usize, const N: usize>(m: &Matrix<T, M, N>) -> Vec<T> {
+    m.data.iter().flatten().copied().collect()
+}
+
+pub(crate) fn from_slice<T: Copy, const M: usize, const N: usize>(v: &[T]) -> Matrix<T, M, N> {
+    Matrix {
+        data: array::from_fn(|i| array::from_fn(|j| v[i * N + j])),
+    }
+}
+
+fn matmul<T: Field>(a: &[T], b: &[T], m: usize, k: usize, n: usize) -> Vec<T> {
+    let mut result = vec![T::default(); m * n];
+    for i in 0..m {
+        for j in 0..n {
+            result[i * n + j] = (0..k).map(|l| a[i * k + l] * b[l * n + j]).sum();
+        }
+    }
+    result
+}
+
+fn transpose<T: Copy>(a: &[T], m: usize, n: usize) -> Vec<T> {
+    (0..n)
+        .flat_map(|j| (0..m).map(move |i| a[i * n + j]))
+        .collect()
+}
+
+impl<T: Field> Tape<T> {
+    pub fn new() -> Self {
+        Self {
+            nodes: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn var<const M: usize, const N: usize>(&self, value: Matrix<T, M, N>) -> Var<'_, T, M, N> {
+        self.push(value, Op::Leaf)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(crate) fn push<const M: usize, const N: usize>(
+        &self,
+        value: Matrix<T, M, N>,
+        op: Op<T>,
+    ) -> Var<'_, T, M, N> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node {
+            rows: M,
+            cols: N,
+            value: to_vec(&value),
+            op,
+        });
+        Var {
+            tape: self,
+            index: nodes.len() - 1,
+            shape: PhantomData,
+        }
+    }
+}
+
+impl<'t, T: Field, const M: usize, const N: usize> Var<'t, T, M, N> {
+    pub fn value(&self) -> Matrix<T, M, N> {
+        from_slice(&self.tape.nodes.borrow()[self.index].value)
+    }
+}
+
+impl<'t, T: Field> Var<'t, T, 1, 1> {
+    pub fn backward(&self) -> Gradients<'t, T> {
+        let nodes = self.tape.nodes.borrow();
+        let mut adjoints: Vec<Vec<T>> = nodes
+            .iter()
+            .map(|node| vec![T::default(); node.rows * node.cols])
+            .collect();
+        adjoints[self.index][0] = T::one();
+
+        for index in (0..=self.index).rev() {
+            let node = &nodes[index];
+            let g = adjoints[index].clone();
+            let (m, n) = (node.rows, node.cols);
+            let mut accumulate = |target: usize, delta: Vec<T>| {
+                for (a, d) in adjoints[target].iter_mut().zip(delta) {
+                    *a = *a + d;
+                }
+            };
+            match &node.op {
+                Op::Leaf => {}
+                &Op::Add(a, b) => {
+                    accumulate(a, g.clone());
+                    accumulate(b, g);
+                }
+                &Op::Sub(a, b) => {
+                    accumulate(a, g.clone());
+                    accumulate(b, g.iter().map(|&x| -x).collect());
+                }
+                &Op::Neg(a) => accumulate(a, g.iter().map(|&x| -x).collect()),
+                &Op::Scale(a, c) => accumulate(a, g.iter().map(|&x| x * c).collect()),
+                &Op::Hadamard(a, b) => {
+                    let (va, vb) = (&nodes[a].value, &nodes[b].value);
+                    accumulate(a, g.iter().zip(vb).map(|(&x, &y)| x * y).collect());
+                    accumulate(b, g.iter().zip(va).map(|(&x, &y)| x * y).collect());
+                }
+                &Op::MatMul(a, b) => {
+                    // C = A B gives dA = G Bᵀ and dB = Aᵀ G.
+                    let k = nodes[a].cols;
+                    let bt = transpose(&nodes[b].value, k, n);
+                    let at = transpose(&nodes[a].value, m, k);
+                    accumulate(a, matmul(&g, &bt, m, n, k));
+                    accumulate(b, matmul(&at, &g, k, m, n));
+                }
+                &Op::Transpose(a) => accumulate(a, transpose(&g, m, n)),
+                &Op::Sum(a) => {
+                    accumulate(a, vec![g[0]; nodes[a].rows * nodes[a].cols]);
+                }
+                &Op::Inverse(a) => {
+                    // C = A⁻¹ gives dA = -Cᵀ G Cᵀ.
+                    let ct = transpose(&node.value, m, m);
+                    let product = matmul(&matmul(&ct, &g, m, m, m), &ct, m, m, m);
+                    accumulate(a, product.iter().map(|&x| -x).collect());
+                }
+                Op::Determinant(a, cofactors) => {
+                    accumulate(*a, cofactors.iter().map(|&c| g[0] * c).collect());
+                }
+            }
+        }
+        Gradients {
+            tape: self.tape,
+            adjoints,
+        }
+    }
+}
+
+impl<T: Field> Gradients<'_, T> {
+    /// Zero for variables recorded after `backward`. Panics for another tape.
+    pub fn wrt<const M: usize, const N: usize>(&self, var: &Var<'_, T, M, N>) -> Matrix<T, M, N> {
+        assert!(
+            core::ptr::eq(self.tape, var.tape),
+            "variable belongs to a different tape"
+        );
+        match self.adjoints.get(var.index) {
+            Some(adjoint) => from_slice(adjoint),
+            None => Matrix::zeros(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leaf_gradient() {
+        let tape = Tape::new();
+        let x = tape.var(Matrix::new([[3.0]]));
+        let grads = x.backward();
+        assert_eq!(grads.wrt(&x).data, [[1.0]]);
+    }
+
+    #[test]
+    fn test_transpose_helper() {
+        assert_eq!(transpose(&[1, 2, 3, 4, 5, 6], 2, 3), vec![1, 4, 2, 5, 3, 6]);
+    }
+}
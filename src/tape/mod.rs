@@ -0,0 +1,4 @@
+pub mod core;
+pub mod ops;
+
+pub use self::core::{Gradients, Tape, Var};
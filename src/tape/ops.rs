@@ -0,0 +1,130 @@
+use super::core::{Op, Tape, Var};
+use crate::matrix::Matrix;
+use crate::scalar::Field;
+use core::ops::{Add, Mul, Neg, Sub};
+
+impl<'t, T: Field, const M: usize, const N: usize> Add for Var<'t, T, M, N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        same_tape(self.tape, rhs.tape);
+        self.tape
+            .push(self.value() + rhs.value(), Op::Add(self.index, rhs.index))
+    }
+}
+
+impl<'t, T: Field, const M: usize, const N: usize> Sub for Var<'t, T, M, N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        same_tape(self.tape, rhs.tape);
+        self.tape
+            .push(self.value() - rhs.value(), Op::Sub(self.index, rhs.index))
+    }
+}
+
+impl<'t, T: Field, const M: usize, const N: usize> Neg for Var<'t, T, M, N> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.tape.push(-self.value(), Op::Neg(self.index))
+    }
+}
+
+impl<'t, T: Field, const M: usize, const N: usize> Mul<T> for Var<'t, T, M, N> {
+    type Output = Self;
+    fn mul(self, scalar: T) -> Self::Output {
+        self.tape
+            .push(self.value() * scalar, Op::Scale(self.index, scalar))
+    }
+}
+
+impl<'t, T: Field, const M: usize, const K: usize, const N: usize> Mul<Var<'t, T, K, N>>
+    for Var<'t, T, M, K>
+{
+    type Output = Var<'t, T, M, N>;
+    fn mul(self, rhs: Var<'t, T, K, N>) -> Self::Output {
+        same_tape(self.tape, rhs.tape);
+        self.tape.push(
+            self.value() * rhs.value(),
+            Op::MatMul(self.index, rhs.index),
+        )
+    }
+}
+
+impl<'t, T: Field, const M: usize, const N: usize> Var<'t, T, M, N> {
+    pub fn transpose(&self) -> Var<'t, T, N, M> {
+        self.tape
+            .push(self.value().transpose(), Op::Transpose(self.index))
+    }
+
+    pub fn hadamard(&self, other: &Self) -> Self {
+        same_tape(self.tape, other.tape);
+        self.tape.push(
+            self.value().hadamard(&other.value()),
+            Op::Hadamard(self.index, other.index),
+        )
+    }
+
+    pub fn sum(&self) -> Var<'t, T, 1, 1> {
+        let value = self.value().data.iter().flatten().copied().sum();
+        self.tape.push(Matrix::new([[value]]), Op::Sum(self.index))
+    }
+}
+
+impl<'t, T: Field, const M: usize> Var<'t, T, M, M> {
+    /// Records nothing if the value is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let inverse = self.value().inverse()?;
+        Some(self.tape.push(inverse, Op::Inverse(self.index)))
+    }
+
+    pub fn determinant(&self) -> Var<'t, T, 1, 1> {
+        let value = self.value();
+        let det = value.determinant();
+        let cofactors = cofactors(&value, det);
+        self.tape
+            .push(Matrix::new([[det]]), Op::Determinant(self.index, cofactors))
+    }
+}
+
+/// Row-major. Without an inverse, cofactor `(i, j)` is the determinant of
+/// `A` with row `i` replaced by the `j`th unit row.
+fn cofactors<T: Field, const M: usize>(a: &Matrix<T, M, M>, det: T) -> Vec<T> {
+    if let Some(inverse) = a.inverse() {
+        return super::core::to_vec(&(inverse.transpose() * det));
+    }
+    let mut result = Vec::with_capacity(M * M);
+    for i in 0..M {
+        for j in 0..M {
+            let mut replaced = *a;
+            replaced[i].fill(T::default());
+            replaced[i][j] = T::one();
+            result.push(replaced.determinant());
+        }
+    }
+    result
+}
+
+fn same_tape<T>(a: &Tape<T>, b: &Tape<T>) {
+    assert!(
+        core::ptr::eq(a, b),
+        "cannot combine variables from different tapes"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cofactors_of_singular() {
+        let a = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(cofactors(&a, 0.0), vec![4.0, -2.0, -2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_sum_gradient() {
+        let tape = Tape::new();
+        let x = tape.var(Matrix::new([[1.0, 2.0]]));
+        let grads = x.hadamard(&x).sum().backward();
+        assert_eq!(grads.wrt(&x).data, [[2.0, 4.0]]);
+    }
+}
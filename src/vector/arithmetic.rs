@@ -1,4 +1,6 @@
 use super::core::Vector;
+use crate::matrix::Matrix;
+use crate::scalar::Conjugate;
 use core::array;
 use core::ops::{Add, Mul, Sub};
 
@@ -39,6 +41,28 @@ impl<T: Mul<Output = T> + Add<Output = T> + Default + Copy, const N: usize> Vect
     }
 }
 
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Mul<Output = T> + Add<Output = T> + Default + Copy + Conjugate,
+{
+    /// Conjugate-linear in `self`.
+    pub fn hermitian_dot(&self, other: &Self) -> T {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(&a, &b)| a.conjugate() * b)
+            .fold(T::default(), |acc, x| acc + x)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy, const N: usize> Vector<T, N> {
+    pub fn outer<const K: usize>(&self, other: &Vector<T, K>) -> Matrix<T, N, K> {
+        Matrix {
+            data: array::from_fn(|i| array::from_fn(|j| self.data[i] * other.data[j])),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,4 +81,11 @@ mod tests {
         let v2 = Vector::new([3.0, 4.0]);
         assert_eq!(v1.dot(&v2), 11.0);
     }
+
+    #[test]
+    fn test_outer() {
+        let v1 = Vector::new([1, 2]);
+        let v2 = Vector::new([3, 4, 5]);
+        assert_eq!(v1.outer(&v2).data, [[3, 4, 5], [6, 8, 10]]);
+    }
 }
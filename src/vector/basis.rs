@@ -0,0 +1,112 @@
+use super::core::Vector;
+use crate::matrix::Matrix;
+use crate::scalar::Real;
+use core::array;
+use core::fmt;
+
+/// Returned when the input at `index` lies in the span of the ones before it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LinearDependenceError {
+    pub index: usize,
+}
+
+impl fmt::Display for LinearDependenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "vector {} is linearly dependent on the preceding vectors",
+            self.index
+        )
+    }
+}
+
+impl std::error::Error for LinearDependenceError {}
+
+impl<T: Real, const N: usize> Vector<T, N> {
+    pub fn orthonormalize<const K: usize>(
+        vectors: [Self; K],
+    ) -> Result<[Self; K], LinearDependenceError> {
+        let mut basis = vectors;
+        for k in 0..K {
+            let length = vectors[k].magnitude();
+            let residual = vectors[k].residual(&basis[..k]);
+            let remaining = residual.magnitude();
+            if length == T::default() || (remaining / length).is_negligible() {
+                return Err(LinearDependenceError { index: k });
+            }
+            basis[k] = residual * (T::one() / remaining);
+        }
+        Ok(basis)
+    }
+
+    /// The first `K` vectors are `orthonormalize(partial)`.
+    pub fn complete_basis<const K: usize>(
+        partial: [Self; K],
+    ) -> Result<[Self; N], LinearDependenceError> {
+        const { assert!(K <= N, "a partial basis has at most N vectors") }
+        let zero = Self::new([T::default(); N]);
+        let mut basis = [zero; N];
+        basis[..K].copy_from_slice(&Self::orthonormalize(partial)?);
+        for k in K..N {
+            let (mut best, mut best_length) = (zero, T::default());
+            for axis in 0..N {
+                let mut e = zero;
+                e.data[axis] = T::one();
+                let residual = e.residual(&basis[..k]);
+                let length = residual.magnitude();
+                if length > best_length {
+                    best = residual;
+                    best_length = length;
+                }
+            }
+            basis[k] = best * (T::one() / best_length);
+        }
+        Ok(basis)
+    }
+
+    fn residual(&self, basis: &[Self]) -> Self {
+        let mut w = *self;
+        for _ in 0..2 {
+            for q in basis {
+                w = w - *q * q.dot(&w);
+            }
+        }
+        w
+    }
+}
+
+impl<T: Real, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// The `Q` factor of a thin QR decomposition.
+    pub fn orthonormalize_columns(&self) -> Result<Self, LinearDependenceError> {
+        let columns: [Vector<T, M>; N] =
+            array::from_fn(|j| Vector::new(array::from_fn(|i| self[i][j])));
+        let q = Vector::orthonormalize(columns)?;
+        Ok(Matrix::new(array::from_fn(|i| {
+            array::from_fn(|j| q[j].data[i])
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orthonormalize() {
+        let [a, b] =
+            Vector::orthonormalize([Vector::new([3.0, 4.0]), Vector::new([1.0, 0.0])]).unwrap();
+        assert!(a.distance(&Vector::new([0.6, 0.8])) < 1e-15);
+        assert!(a.dot(&b).abs() < 1e-15);
+        assert!((b.magnitude() - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_dependent_input_reported() {
+        let result = Vector::orthonormalize([
+            Vector::new([1.0, 2.0, 3.0]),
+            Vector::new([0.0, 1.0, 0.0]),
+            Vector::new([2.0, 5.0, 6.0]),
+        ]);
+        assert_eq!(result, Err(LinearDependenceError { index: 2 }));
+    }
+}
@@ -0,0 +1,43 @@
+use super::core::Vector;
+use core::array;
+
+impl<T: Copy, const N: usize> Vector<T, N> {
+    pub fn concat<const K: usize, const NK: usize>(&self, other: &Vector<T, K>) -> Vector<T, NK> {
+        const { assert!(NK == N + K, "concatenated length must be N + K") }
+        Vector {
+            data: array::from_fn(|i| {
+                if i < N {
+                    self.data[i]
+                } else {
+                    other.data[i - N]
+                }
+            }),
+        }
+    }
+
+    pub fn split<const A: usize, const B: usize>(&self) -> (Vector<T, A>, Vector<T, B>) {
+        const { assert!(A + B == N, "split lengths must add up to N") }
+        (
+            Vector {
+                data: array::from_fn(|i| self.data[i]),
+            },
+            Vector {
+                data: array::from_fn(|i| self.data[A + i]),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concat_split() {
+        let v = Vector::new([1, 2]);
+        let w = Vector::new([3]);
+        let joined: Vector<i32, 3> = v.concat(&w);
+        assert_eq!(joined.data, [1, 2, 3]);
+        assert_eq!(joined.split::<2, 1>(), (v, w));
+    }
+}
@@ -28,9 +28,7 @@ where
                 } else {
                     (abs_val.log10().floor() as i32 + 1) as usize
                 };
-                if abs_val < 1e-3
-                    || abs_val >= 1e4
-                    || (abs_val >= 1.0 && digits_before > sig_digits)
+                if !(1e-3..1e4).contains(&abs_val) || (abs_val >= 1.0 && digits_before > sig_digits)
                 {
                     write!(f, "{:.2e}", value.into())?;
                 } else {
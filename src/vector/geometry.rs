@@ -1,16 +1,110 @@
 use super::core::Vector;
+use crate::matrix::Matrix;
+use crate::scalar::{Angle, EuclideanNorm, One, Real};
 use core::array;
-use core::ops::{Add, Div, Mul, Sub};
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
-impl<T: Copy + Into<f64>, const N: usize> Vector<T, N> {
-    pub fn magnitude(&self) -> f64 {
-        self.data
-            .iter()
-            .fold(0.0, |acc, &component| {
-                let c: f64 = component.into();
-                acc + c * c
-            })
-            .sqrt()
+/// Returned when normalizing a vector of length zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ZeroLengthError;
+
+impl fmt::Display for ZeroLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cannot normalize a zero-length vector")
+    }
+}
+
+impl std::error::Error for ZeroLengthError {}
+
+impl<T: EuclideanNorm, const N: usize> Vector<T, N> {
+    pub fn magnitude(&self) -> T::Output {
+        T::euclidean_norm(&self.data)
+    }
+}
+
+impl<T: Real, const N: usize> Vector<T, N> {
+    pub fn normalize(&self) -> Result<Self, ZeroLengthError> {
+        let length = self.magnitude();
+        if length == T::default() {
+            return Err(ZeroLengthError);
+        }
+        Ok(*self * (T::one() / length))
+    }
+
+    /// Like `normalize`, but also rejects vectors no longer than `min_length`.
+    pub fn try_normalize(&self, min_length: T) -> Option<Self> {
+        let length = self.magnitude();
+        if length <= min_length || length == T::default() {
+            return None;
+        }
+        Some(*self * (T::one() / length))
+    }
+
+    pub fn distance_squared(&self, other: &Self) -> T {
+        let d = *self - *other;
+        d.dot(&d)
+    }
+
+    pub fn distance(&self, other: &Self) -> T {
+        self.distance_squared(other).sqrt()
+    }
+
+    /// In `[0, π]`; zero when either vector is zero.
+    pub fn angle_between(&self, other: &Self) -> Angle<T> {
+        let u = *self * other.magnitude();
+        let v = *other * self.magnitude();
+        let two = T::one() + T::one();
+        Angle::atan2((u - v).magnitude(), (u + v).magnitude()) * two
+    }
+
+    /// Zero if `onto` is zero.
+    pub fn project_onto(&self, onto: &Self) -> Self {
+        let denom = onto.dot(onto);
+        if denom == T::default() {
+            return Self {
+                data: [T::default(); N],
+            };
+        }
+        *onto * (self.dot(onto) / denom)
+    }
+
+    pub fn reject_from(&self, from: &Self) -> Self {
+        *self - self.project_onto(from)
+    }
+
+    /// `normal` need not be unit length.
+    pub fn reflect(&self, normal: &Self) -> Self {
+        let two = T::one() + T::one();
+        *self - self.project_onto(normal) * two
+    }
+
+    /// Both vectors are unit length and face each other; `eta` is the ratio of
+    /// refractive indices. `None` on total internal reflection.
+    pub fn refract(&self, normal: &Self, eta: T) -> Option<Self> {
+        let cos_i = normal.dot(self);
+        let k = T::one() - eta * eta * (T::one() - cos_i * cos_i);
+        if k < T::default() {
+            return None;
+        }
+        Some(*self * eta - *normal * (eta * cos_i + k.sqrt()))
+    }
+
+    /// Within `tolerance` of 0 or π; true for a zero vector.
+    pub fn is_parallel(&self, other: &Self, tolerance: impl Into<Angle<T>>) -> bool {
+        let tolerance = tolerance.into().radians();
+        let angle = self.angle_between(other).radians();
+        angle <= tolerance || T::pi() - angle <= tolerance
+    }
+
+    /// Within `tolerance` of π/2; true for a zero vector.
+    pub fn is_orthogonal(&self, other: &Self, tolerance: impl Into<Angle<T>>) -> bool {
+        let scale = self.magnitude() * other.magnitude();
+        if scale == T::default() {
+            return true;
+        }
+        let two = T::one() + T::one();
+        (self.angle_between(other).radians() - T::pi() / two).abs() <= tolerance.into().radians()
     }
 }
 
@@ -22,27 +116,98 @@ impl<T: Copy + Add<Output = T> + Div<Output = T> + From<u8>, const N: usize> Vec
     }
 }
 
-impl<T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T> + From<f64>> Vector<T, 2> {
-    pub fn rotate(&self, angle_degrees: f64) -> Self {
-        let angle_radians = angle_degrees.to_radians();
-        let cos = angle_radians.cos();
-        let sin = angle_radians.sin();
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>, const N: usize> Vector<T, N> {
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        Self {
+            data: array::from_fn(|i| self.data[i] + (other.data[i] - self.data[i]) * t),
+        }
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Copy + Default + One + PartialEq + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    pub fn centroid(points: &[Self]) -> Option<Self> {
+        Self::weighted_centroid(points, &vec![T::one(); points.len()])
+    }
+
+    /// `None` if the weights sum to zero or there is not one per point.
+    pub fn weighted_centroid(points: &[Self], weights: &[T]) -> Option<Self> {
+        if points.len() != weights.len() {
+            return None;
+        }
+        let total = weights.iter().fold(T::default(), |acc, &w| acc + w);
+        if total == T::default() {
+            return None;
+        }
+        let mut sum = [T::default(); N];
+        for (point, &w) in points.iter().zip(weights) {
+            for (s, &c) in sum.iter_mut().zip(&point.data) {
+                *s = *s + c * w;
+            }
+        }
+        Some(Self {
+            data: sum.map(|s| s / total),
+        })
+    }
+}
+
+impl<T: Real> Vector<T, 2> {
+    /// Counterclockwise.
+    pub fn rotate(&self, angle: impl Into<Angle<T>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
 
         let x = self.data[0];
         let y = self.data[1];
 
         Self {
-            data: [
-                T::from(cos) * x - T::from(sin) * y,
-                T::from(sin) * x + T::from(cos) * y,
-            ],
+            data: [cos * x - sin * y, sin * x + cos * y],
         }
     }
 }
 
+impl<T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>> Vector<T, 3> {
+    pub fn cross(&self, other: &Self) -> Self {
+        let [a1, a2, a3] = self.data;
+        let [b1, b2, b3] = other.data;
+        Self {
+            data: [a2 * b3 - a3 * b2, a3 * b1 - a1 * b3, a1 * b2 - a2 * b1],
+        }
+    }
+
+    /// `self · (b × c)`.
+    pub fn scalar_triple(&self, b: &Self, c: &Self) -> T {
+        let bc = b.cross(c);
+        self.data[0] * bc.data[0] + self.data[1] * bc.data[1] + self.data[2] * bc.data[2]
+    }
+
+    /// `self × (b × c)`.
+    pub fn vector_triple(&self, b: &Self, c: &Self) -> Self {
+        self.cross(&b.cross(c))
+    }
+}
+
+impl<T: Copy + Default + Neg<Output = T>> Vector<T, 3> {
+    /// The skew-symmetric matrix `[v]ₓ` with `[v]ₓ w = v × w`.
+    pub fn cross_matrix(&self) -> Matrix<T, 3, 3> {
+        let [x, y, z] = self.data;
+        let zero = T::default();
+        Matrix::new([[zero, -z, y], [z, zero, -x], [-y, x, zero]])
+    }
+}
+
+impl<T: Copy + Mul<Output = T> + Sub<Output = T>> Vector<T, 2> {
+    /// The scalar cross product `x₁ y₂ - y₁ x₂`.
+    pub fn perp_dot(&self, other: &Self) -> T {
+        self.data[0] * other.data[1] - self.data[1] * other.data[0]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scalar::Deg;
 
     #[test]
     fn test_magnitude() {
@@ -53,8 +218,24 @@ mod tests {
     #[test]
     fn test_rotate() {
         let v: Vector<f64, 2> = Vector::new([1.0, 0.0]);
-        let rotated = v.rotate(90.0);
+        let rotated = v.rotate(Deg(90.0));
         assert!((rotated.data[0] - 0.0).abs() < 1e-9);
         assert!((rotated.data[1] - 1.0).abs() < 1e-9);
     }
+
+    #[test]
+    fn test_cross() {
+        let x = Vector::new([1, 0, 0]);
+        let y = Vector::new([0, 1, 0]);
+        assert_eq!(x.cross(&y).data, [0, 0, 1]);
+        assert_eq!(Vector::new([1, 0]).perp_dot(&Vector::new([0, 1])), 1);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let v: Vector<f64, 2> = Vector::new([3.0, 4.0]);
+        let n = v.normalize().unwrap();
+        assert!((n.data[0] - 0.6).abs() < 1e-12 && (n.data[1] - 0.8).abs() < 1e-12);
+        assert_eq!(Vector::new([0.0, 0.0]).normalize(), Err(ZeroLengthError));
+    }
 }
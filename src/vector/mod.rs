@@ -1,4 +1,6 @@
 pub mod arithmetic;
+pub mod basis;
+pub mod blocks;
 pub mod core;
 pub mod display;
 pub mod geometry;
@@ -1 +1,6 @@
+mod bit_matrix;
+mod common;
 mod matrix;
+mod primitives;
+mod tape;
+mod vector;
@@ -0,0 +1,71 @@
+use matops::{BitMatrix, Matrix, Vector};
+
+fn hamming_parity_check() -> BitMatrix<3, 7> {
+    BitMatrix::from(Matrix::new([
+        [1u8, 0, 1, 0, 1, 0, 1],
+        [0, 1, 1, 0, 0, 1, 1],
+        [0, 0, 0, 1, 1, 1, 1],
+    ]))
+}
+
+fn bits<const N: usize>(data: [u8; N]) -> Vector<bool, N> {
+    Vector::new(data.map(|b| b == 1))
+}
+
+#[test]
+fn test_hamming_code_nullspace() {
+    let h = hamming_parity_check();
+    assert_eq!(h.rank(), 3);
+    let codewords = h.nullspace();
+    assert_eq!(codewords.len(), 4);
+    for c in codewords {
+        assert_eq!(&h * c, bits([0, 0, 0]));
+    }
+}
+
+#[test]
+fn test_syndrome_decoding() {
+    let h = hamming_parity_check();
+    // Flipping bit 5 (1-based) of a codeword gives the syndrome 101, i.e. 5 in binary.
+    let received = bits([1, 1, 1, 0, 1, 0, 0]);
+    assert_eq!(&h * received, bits([1, 0, 1]));
+}
+
+#[test]
+fn test_solve_xor_system() {
+    // x0 ^ x1 = 1, x1 ^ x2 = 0, x0 ^ x2 = 1
+    let a = BitMatrix::from(Matrix::new([[1u8, 1, 0], [0, 1, 1], [1, 0, 1]]));
+    let b = bits([1, 0, 1]);
+    let x = a.solve(&b).unwrap();
+    assert_eq!(&a * x, b);
+
+    // x0 ^ x1 ^ (x1 ^ x2) = x0 ^ x2, so this right-hand side is inconsistent.
+    assert!(a.solve(&bits([1, 0, 0])).is_none());
+}
+
+#[test]
+fn test_multiplication_matches_matrix_u8() {
+    let a: Matrix<u8, 2, 3> = Matrix::new([[1, 1, 0], [1, 1, 1]]);
+    let b: Matrix<u8, 3, 2> = Matrix::new([[1, 0], [1, 1], [0, 1]]);
+    let product = BitMatrix::from(a) * BitMatrix::from(b);
+    let mut expected = a * b;
+    for i in 0..2 {
+        for j in 0..2 {
+            expected[i][j] %= 2;
+        }
+    }
+    assert_eq!(Matrix::<u8, 2, 2>::from(&product), expected);
+}
+
+#[test]
+fn test_wide_matrix_spans_words() {
+    let mut a: BitMatrix<3, 130> = BitMatrix::zeros();
+    a.set(0, 0, true);
+    a.set(0, 129, true);
+    a.set(1, 64, true);
+    a.set(2, 129, true);
+    assert_eq!(a.rank(), 3);
+    assert_eq!(a.nullspace().len(), 127);
+    assert_eq!(a.transpose().transpose(), a);
+    assert_eq!(&a + &a, BitMatrix::zeros());
+}
@@ -0,0 +1,35 @@
+use matops::{Matrix, Vector};
+
+pub fn assert_matrix_approx_eq<const M: usize, const N: usize>(
+    a: Matrix<f64, M, N>,
+    b: Matrix<f64, M, N>,
+    tolerance: f64,
+) {
+    for i in 0..M {
+        for j in 0..N {
+            assert!(
+                (a[i][j] - b[i][j]).abs() < tolerance,
+                "Matrices differ at [{}][{}]: {} != {} (difference: {})",
+                i,
+                j,
+                a[i][j],
+                b[i][j],
+                (a[i][j] - b[i][j]).abs()
+            );
+        }
+    }
+}
+
+pub fn assert_vector_approx_eq<const N: usize>(
+    a: Vector<f64, N>,
+    b: Vector<f64, N>,
+    tolerance: f64,
+) {
+    assert!(
+        a.distance(&b) < tolerance,
+        "Vectors differ: {:?} != {:?} (distance: {})",
+        a,
+        b,
+        a.distance(&b)
+    );
+}
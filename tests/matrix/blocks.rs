@@ -0,0 +1,53 @@
+use super::*;
+use matops::{Matrix, Vector};
+
+#[test]
+fn test_kkt_system() {
+    let a: Matrix<f64, 2, 2> = Matrix::new([[4.0, 1.0], [1.0, 3.0]]);
+    let b: Matrix<f64, 1, 2> = Matrix::new([[1.0, 1.0]]);
+    let zero: Matrix<f64, 1, 1> = Matrix::zeros();
+    let kkt: Matrix<f64, 3, 3> = Matrix::from_blocks(&a, &b.transpose(), &b, &zero);
+    let expected = Matrix::new([[4.0, 1.0, 1.0], [1.0, 3.0, 1.0], [1.0, 1.0, 0.0]]);
+    assert_matrix_approx_eq(kkt, expected, 1e-9);
+}
+
+#[test]
+fn test_homogeneous_transform() {
+    let r: Matrix<i32, 2, 2> = Matrix::new([[0, -1], [1, 0]]);
+    let t: Matrix<i32, 2, 1> = Matrix::new([[5], [7]]);
+    let bottom: Matrix<i32, 1, 2> = Matrix::zeros();
+    let one: Matrix<i32, 1, 1> = Matrix::identity();
+    let h: Matrix<i32, 3, 3> = Matrix::from_blocks(&r, &t, &bottom, &one);
+    assert_eq!(h * Vector::new([1, 0, 1]), Vector::new([5, 8, 1]));
+}
+
+#[test]
+fn test_stack_and_split_round_trip() {
+    let top: Matrix<i32, 1, 3> = Matrix::new([[1, 2, 3]]);
+    let bottom: Matrix<i32, 2, 3> = Matrix::new([[4, 5, 6], [7, 8, 9]]);
+    let m: Matrix<i32, 3, 3> = top.vstack(&bottom);
+    assert_eq!(m.split_at_row::<1, 2>(), (top, bottom));
+
+    let (left, right) = m.split_at_col::<2, 1>();
+    let rejoined: Matrix<i32, 3, 3> = left.hstack(&right);
+    assert_eq!(rejoined, m);
+    assert_eq!(right, Matrix::new([[3], [6], [9]]));
+}
+
+#[test]
+fn test_block_extraction() {
+    let m: Matrix<i32, 3, 4> = Matrix::new([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+    assert_eq!(m.block::<0, 0, 3, 4>(), m);
+    assert_eq!(m.block::<2, 1, 1, 3>(), Matrix::new([[10, 11, 12]]));
+}
+
+#[test]
+fn test_vector_concat_split() {
+    let v: Vector<f64, 2> = Vector::new([1.0, 2.0]);
+    let w: Vector<f64, 2> = Vector::new([3.0, 4.0]);
+    let joined: Vector<f64, 4> = v.concat(&w);
+    assert_eq!(joined, Vector::new([1.0, 2.0, 3.0, 4.0]));
+    let (head, tail) = joined.split::<1, 3>();
+    assert_eq!(head, Vector::new([1.0]));
+    assert_eq!(tail, Vector::new([2.0, 3.0, 4.0]));
+}
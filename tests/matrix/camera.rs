@@ -0,0 +1,156 @@
+use crate::common::{assert_matrix_approx_eq, assert_vector_approx_eq};
+use matops::scalar::Rad;
+use matops::{Matrix, Vector};
+use std::f64::consts::FRAC_PI_2;
+
+#[test]
+fn test_perspective_reference_values() {
+    // gluPerspective(90, 4/3, 1, 10)
+    let p = Matrix::perspective(Rad(FRAC_PI_2), 4.0 / 3.0, 1.0, 10.0);
+    let expected = Matrix::new([
+        [0.75, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, -11.0 / 9.0, -20.0 / 9.0],
+        [0.0, 0.0, -1.0, 0.0],
+    ]);
+    assert_matrix_approx_eq(p, expected, 1e-12);
+
+    // Near and far planes map to -1 and 1 along the view axis.
+    assert_vector_approx_eq(
+        p.project(&Vector::new([0.0, 0.0, -1.0])).unwrap(),
+        Vector::new([0.0, 0.0, -1.0]),
+        1e-9,
+    );
+    assert_vector_approx_eq(
+        p.project(&Vector::new([0.0, 0.0, -10.0])).unwrap(),
+        Vector::new([0.0, 0.0, 1.0]),
+        1e-9,
+    );
+    // The top edge of the field of view maps to y = 1.
+    assert_vector_approx_eq(
+        p.project(&Vector::new([0.0, 5.0, -5.0])).unwrap(),
+        Vector::new([0.0, 1.0, 7.0 / 9.0]),
+        1e-9,
+    );
+    assert!(p.project(&Vector::new([1.0, 1.0, 0.0])).is_none());
+}
+
+#[test]
+fn test_left_handed_mirrors_z() {
+    let rh = Matrix::perspective(Rad(1.0), 1.5, 0.1, 100.0);
+    let lh = Matrix::perspective_lh(Rad(1.0), 1.5, 0.1, 100.0);
+    let p = Vector::new([0.3, -0.2, 4.0]);
+    let mirrored = Vector::new([0.3, -0.2, -4.0]);
+    assert_vector_approx_eq(
+        lh.project(&p).unwrap(),
+        rh.project(&mirrored).unwrap(),
+        1e-9,
+    );
+    assert_eq!(lh[3], [0.0, 0.0, 1.0, 0.0]);
+
+    let ortho_lh = Matrix::orthographic_lh(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0);
+    assert_vector_approx_eq(
+        ortho_lh.project(&Vector::new([1.0, -1.0, 3.0])).unwrap(),
+        Vector::new([1.0, -1.0, 1.0]),
+        1e-9,
+    );
+    let frustum_lh = Matrix::frustum_lh(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0);
+    assert_vector_approx_eq(
+        frustum_lh.project(&Vector::new([1.0, 1.0, 1.0])).unwrap(),
+        Vector::new([1.0, 1.0, -1.0]),
+        1e-9,
+    );
+}
+
+#[test]
+fn test_infinite_and_reversed_z() {
+    let (fovy, aspect, near, far) = (1.2, 16.0 / 9.0, 0.5, 50.0);
+    let infinite = Matrix::perspective_infinite(Rad(fovy), aspect, near);
+    let depth =
+        |m: Matrix<f64, 4, 4>, z: f64| m.project(&Vector::new([0.0, 0.0, z])).unwrap().data[2];
+    assert!((depth(infinite, -near) + 1.0).abs() < 1e-12);
+    assert!((depth(infinite, -1e12) - 1.0).abs() < 1e-9);
+
+    let reversed = Matrix::perspective_reversed_z(Rad(fovy), aspect, near, far);
+    assert!((depth(reversed, -near) - 1.0).abs() < 1e-12);
+    assert!(depth(reversed, -far).abs() < 1e-12);
+    assert!(depth(reversed, -2.0) > depth(reversed, -3.0));
+
+    let reversed_infinite = Matrix::perspective_reversed_z_infinite(Rad(fovy), aspect, near);
+    assert!((depth(reversed_infinite, -near) - 1.0).abs() < 1e-12);
+    assert!(depth(reversed_infinite, -1e12).abs() < 1e-9);
+
+    // x and y agree with the standard projection.
+    let standard = Matrix::perspective(Rad(fovy), aspect, near, far);
+    let p = Vector::new([0.7, -0.3, -4.0]);
+    let (a, b) = (standard.project(&p).unwrap(), reversed.project(&p).unwrap());
+    assert!((a.data[0] - b.data[0]).abs() < 1e-12 && (a.data[1] - b.data[1]).abs() < 1e-12);
+}
+
+#[test]
+fn test_orthographic_reference_values() {
+    // glOrtho(-2, 2, -1, 1, 1, 5)
+    let o = Matrix::orthographic(-2.0, 2.0, -1.0, 1.0, 1.0, 5.0);
+    let expected = Matrix::new([
+        [0.5, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, -0.5, -1.5],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    assert_matrix_approx_eq(o, expected, 1e-12);
+}
+
+#[test]
+fn test_look_at() {
+    let up = Vector::new([0.0, 1.0, 0.0]);
+    // gluLookAt from +z toward the origin is a pure translation.
+    let view = Matrix::look_at(&Vector::new([0.0, 0.0, 5.0]), &Vector::new([0.0; 3]), &up).unwrap();
+    assert_matrix_approx_eq(
+        view,
+        Matrix::translation(&Vector::new([0.0, 0.0, -5.0])),
+        1e-12,
+    );
+
+    // Looking down +x: the target lands on -z, and world +z is to the right.
+    let eye = Vector::new([1.0, 2.0, 3.0]);
+    let view = Matrix::look_at(&eye, &Vector::new([4.0, 2.0, 3.0]), &up).unwrap();
+    assert_vector_approx_eq(
+        view.transform_point(&Vector::new([4.0, 2.0, 3.0])).unwrap(),
+        Vector::new([0.0, 0.0, -3.0]),
+        1e-9,
+    );
+    assert_vector_approx_eq(
+        view.transform_vector(&Vector::new([0.0, 0.0, 1.0])),
+        Vector::new([1.0, 0.0, 0.0]),
+        1e-9,
+    );
+    assert_eq!(view.determinant().round(), 1.0);
+
+    let view_lh = Matrix::look_at_lh(&eye, &Vector::new([4.0, 2.0, 3.0]), &up).unwrap();
+    assert_vector_approx_eq(
+        view_lh
+            .transform_point(&Vector::new([4.0, 2.0, 3.0]))
+            .unwrap(),
+        Vector::new([0.0, 0.0, 3.0]),
+        1e-9,
+    );
+
+    assert!(Matrix::look_at(&eye, &eye, &up).is_err());
+    assert!(Matrix::look_at(&eye, &Vector::new([1.0, 5.0, 3.0]), &up).is_err());
+}
+
+#[test]
+fn test_project_unproject_round_trip() {
+    let view = Matrix::look_at(
+        &Vector::new([3.0, 4.0, 5.0]),
+        &Vector::new([0.0, 0.5, 0.0]),
+        &Vector::new([0.0, 1.0, 0.0]),
+    )
+    .unwrap();
+    let view_projection = Matrix::perspective(Rad(0.9), 1.5, 0.1, 100.0) * view;
+    let world: Vector<f64, 3> = Vector::new([0.4, -0.2, 1.0]);
+    let ndc = view_projection.project(&world).unwrap();
+    assert!(ndc.data.iter().all(|c| c.abs() <= 1.0));
+    assert_vector_approx_eq(view_projection.unproject(&ndc).unwrap(), world, 1e-9);
+    assert!(Matrix::<f64, 4, 4>::zeros().unproject(&ndc).is_none());
+}
@@ -0,0 +1,80 @@
+use matops::scalar::{Complex, Conjugate};
+use matops::{Matrix, Vector};
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+fn assert_complex_matrix_approx_eq<const M: usize, const N: usize>(
+    a: Matrix<Complex<f64>, M, N>,
+    b: Matrix<Complex<f64>, M, N>,
+) {
+    for i in 0..M {
+        for j in 0..N {
+            assert!(
+                (a[i][j] - b[i][j]).abs() < 1e-9,
+                "Matrices differ at [{}][{}]: {} != {}",
+                i,
+                j,
+                a[i][j],
+                b[i][j]
+            );
+        }
+    }
+}
+
+#[test]
+fn test_adjoint() {
+    let a: Matrix<Complex<f64>, 2, 3> = Matrix::new([
+        [c(1.0, 1.0), c(2.0, 0.0), c(0.0, -3.0)],
+        [c(4.0, 0.0), c(5.0, 2.0), c(6.0, 0.0)],
+    ]);
+    let expected = Matrix::new([
+        [c(1.0, -1.0), c(4.0, 0.0)],
+        [c(2.0, 0.0), c(5.0, -2.0)],
+        [c(0.0, 3.0), c(6.0, 0.0)],
+    ]);
+    assert_eq!(a.adjoint(), expected);
+    assert_eq!(a.adjoint().adjoint(), a);
+}
+
+#[test]
+fn test_hermitian_inner_product() {
+    let v: Vector<Complex<f64>, 2> = Vector::new([c(1.0, 1.0), c(0.0, 2.0)]);
+    // |1+i|² + |2i|² = 2 + 4
+    assert_eq!(v.hermitian_dot(&v), c(6.0, 0.0));
+
+    let w: Vector<Complex<f64>, 2> = Vector::new([c(0.0, 1.0), c(1.0, 0.0)]);
+    assert_eq!(v.hermitian_dot(&w), w.hermitian_dot(&v).conjugate());
+    // The plain dot product stays bilinear: (1+i)² + (2i)² = 2i - 4.
+    assert_eq!(v.dot(&v), c(-4.0, 2.0));
+}
+
+#[test]
+fn test_unitary_hadamard_gate() {
+    let s = core::f64::consts::FRAC_1_SQRT_2;
+    let h: Matrix<Complex<f64>, 2, 2> =
+        Matrix::new([[c(s, 0.0), c(s, 0.0)], [c(s, 0.0), c(-s, 0.0)]]);
+    let phase: Matrix<Complex<f64>, 2, 2> =
+        Matrix::new([[c(1.0, 0.0), c(0.0, 0.0)], [c(0.0, 0.0), c(0.0, 1.0)]]);
+    let u = phase * h;
+    assert_complex_matrix_approx_eq(u.adjoint() * u, Matrix::identity());
+    assert_complex_matrix_approx_eq(u.inverse().unwrap(), u.adjoint());
+}
+
+#[test]
+fn test_complex_determinant_inverse_solve() {
+    let a: Matrix<Complex<f64>, 2, 2> =
+        Matrix::new([[c(1.0, 2.0), c(3.0, 0.0)], [c(0.0, -1.0), c(2.0, 1.0)]]);
+    // (1+2i)(2+i) - 3(-i) = 5i + 3i
+    let det = a.determinant();
+    assert!((det - c(0.0, 8.0)).abs() < 1e-9);
+
+    let inv = a.inverse().unwrap();
+    assert_complex_matrix_approx_eq(a * inv, Matrix::identity());
+
+    let b = Vector::new([c(1.0, 0.0), c(0.0, 1.0)]);
+    let x = a.solve(&b).unwrap();
+    let residual = a * x - b;
+    assert!(residual.hermitian_dot(&residual).abs() < 1e-18);
+}
@@ -0,0 +1,72 @@
+use super::*;
+use matops::scalar::dual::jacobian;
+use matops::scalar::{Dual, DualN};
+use matops::{Matrix, Vector};
+
+fn perturbed<const M: usize>(
+    a: Matrix<f64, M, M>,
+    b: Matrix<f64, M, M>,
+) -> Matrix<Dual<f64>, M, M> {
+    let mut result = Matrix::zeros();
+    for i in 0..M {
+        for j in 0..M {
+            result[i][j] = Dual::new(a[i][j], [b[i][j]]);
+        }
+    }
+    result
+}
+
+#[test]
+fn test_determinant_sensitivity() {
+    // d/dt det(A + tB) at t = 0 is det(A) tr(A⁻¹ B) (Jacobi's formula).
+    let a: Matrix<f64, 3, 3> = Matrix::new([[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]]);
+    let b: Matrix<f64, 3, 3> = Matrix::new([[0.5, 0.0, 1.0], [2.0, -1.0, 0.0], [0.0, 3.0, 1.0]]);
+    let det = perturbed(a, b).determinant();
+    let expected = a.determinant() * (a.inverse().unwrap() * b).trace();
+    assert!((det.re - a.determinant()).abs() < 1e-9);
+    assert!((det.derivative() - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_inverse_sensitivity() {
+    // d/dt (A + tB)⁻¹ at t = 0 is -A⁻¹ B A⁻¹.
+    let a: Matrix<f64, 2, 2> = Matrix::new([[4.0, 7.0], [2.0, 6.0]]);
+    let b: Matrix<f64, 2, 2> = Matrix::new([[1.0, 0.0], [3.0, -2.0]]);
+    let inv = perturbed(a, b).inverse().unwrap();
+    let a_inv = a.inverse().unwrap();
+    let expected = -(a_inv * b * a_inv);
+    let mut derivative: Matrix<f64, 2, 2> = Matrix::zeros();
+    for i in 0..2 {
+        for j in 0..2 {
+            derivative[i][j] = inv[i][j].derivative();
+        }
+    }
+    assert_matrix_approx_eq(derivative, expected, 1e-9);
+}
+
+#[test]
+fn test_jacobian_of_linear_map() {
+    let a: Matrix<f64, 2, 3> = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let lifted: Matrix<DualN<f64, 3>, 2, 3> =
+        Matrix::new(a.data.map(|row| row.map(DualN::constant)));
+    let j = jacobian(|x| lifted * x, Vector::new([0.3, -1.0, 2.0]));
+    assert_matrix_approx_eq(j, a, 1e-12);
+}
+
+#[test]
+fn test_jacobian_of_polar_to_cartesian() {
+    let r = 2.0_f64;
+    let theta = 0.7_f64;
+    let j = jacobian(
+        |v: Vector<DualN<f64, 2>, 2>| {
+            let [r, theta] = v.data;
+            Vector::new([r * theta.cos(), r * theta.sin()])
+        },
+        Vector::new([r, theta]),
+    );
+    let expected = Matrix::new([
+        [theta.cos(), -r * theta.sin()],
+        [theta.sin(), r * theta.cos()],
+    ]);
+    assert_matrix_approx_eq(j, expected, 1e-12);
+}
@@ -0,0 +1,73 @@
+use super::*;
+use matops::Matrix;
+
+#[test]
+fn test_row_operations_match_elementary_matrices() {
+    let a: Matrix<i32, 3, 2> = Matrix::new([[1, 2], [3, 4], [5, 6]]);
+
+    let mut swapped = a;
+    swapped.swap_rows(0, 2);
+    assert_eq!(swapped, Matrix::elementary_swap(0, 2) * a);
+
+    let mut scaled = a;
+    scaled.scale_row(1, -3);
+    assert_eq!(scaled, Matrix::elementary_scale(1, -3) * a);
+
+    let mut added = a;
+    added.add_scaled_row(2, 0, 4);
+    assert_eq!(added, Matrix::elementary_add(2, 0, 4) * a);
+}
+
+#[test]
+fn test_column_operations_match_elementary_matrices() {
+    let a: Matrix<i32, 2, 3> = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+
+    let mut swapped = a;
+    swapped.swap_cols(0, 1);
+    assert_eq!(swapped, a * Matrix::elementary_swap(0, 1));
+
+    let mut scaled = a;
+    scaled.scale_col(2, 2);
+    assert_eq!(scaled, a * Matrix::elementary_scale(2, 2));
+
+    // Column operations act on the right, so the roles of target and source
+    // are transposed in the elementary matrix.
+    let mut added = a;
+    added.add_scaled_col(0, 2, -1);
+    assert_eq!(added, a * Matrix::elementary_add(2, 0, -1));
+}
+
+#[test]
+fn test_elementary_matrix_inverses() {
+    let e: Matrix<f64, 3, 3> = Matrix::elementary_add(1, 0, 2.5);
+    let e_inv: Matrix<f64, 3, 3> = Matrix::elementary_add(1, 0, -2.5);
+    assert_matrix_approx_eq(e * e_inv, Matrix::identity(), 1e-9);
+
+    let s: Matrix<f64, 3, 3> = Matrix::elementary_scale(2, 4.0);
+    assert_matrix_approx_eq(
+        s.inverse().unwrap(),
+        Matrix::elementary_scale(2, 0.25),
+        1e-9,
+    );
+    assert!((s.determinant() - 4.0).abs() < 1e-9);
+}
+
+#[test]
+#[should_panic]
+fn test_scale_col_out_of_bounds() {
+    let mut a: Matrix<i32, 2, 2> = Matrix::zeros();
+    a.scale_col(2, 1);
+}
+
+#[test]
+fn test_add_scaled_same_row_and_col() {
+    let mut a: Matrix<i32, 2, 2> = Matrix::new([[1, 2], [3, 4]]);
+    a.add_scaled_row(1, 1, 2);
+    assert_eq!(a.data, [[1, 2], [9, 12]]);
+    a.add_scaled_col(0, 0, -1);
+    assert_eq!(a.data, [[0, 2], [0, 12]]);
+    assert_eq!(
+        Matrix::<i32, 2, 2>::elementary_add(0, 0, 4),
+        Matrix::new([[5, 0], [0, 1]])
+    );
+}
@@ -0,0 +1,72 @@
+use super::*;
+use matops::matrix::explain::Operation;
+use matops::Matrix;
+
+#[test]
+fn test_inverse_explained_matches_inverse() {
+    let a: Matrix<f64, 3, 3> = Matrix::new([[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]]);
+    let (inverse, explanation) = a.inverse_explained();
+    // Both run the same elimination, so the results agree exactly.
+    assert_eq!(inverse, a.inverse());
+    assert_matrix_approx_eq(a * inverse.unwrap(), Matrix::identity(), 1e-9);
+    assert_eq!(explanation.left, a);
+    assert_eq!(explanation.right, Matrix::identity());
+    assert_eq!(explanation.result().0, Matrix::identity());
+}
+
+#[test]
+fn test_inverse_explained_singular() {
+    let a: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    let (inverse, explanation) = a.inverse_explained();
+    assert!(inverse.is_none());
+    assert_eq!(explanation.result().0, a.rref());
+}
+
+#[test]
+fn test_rref_explained_records_operations() {
+    let a: Matrix<f64, 2, 3> = Matrix::new([[0.0, 2.0, 4.0], [3.0, 3.0, 3.0]]);
+    let explanation = a.rref_explained();
+    assert_eq!(
+        explanation.operations,
+        vec![
+            Operation::Pivot { row: 1, col: 0 },
+            Operation::SwapRows { a: 0, b: 1 },
+            Operation::ScaleRow {
+                row: 0,
+                factor: 1.0 / 3.0
+            },
+            Operation::Pivot { row: 1, col: 1 },
+            Operation::ScaleRow {
+                row: 1,
+                factor: 0.5
+            },
+            Operation::AddScaledRow {
+                target: 0,
+                source: 1,
+                factor: -1.0
+            },
+        ]
+    );
+    assert_eq!(
+        explanation.result().0.data,
+        [[1.0, 0.0, -1.0], [0.0, 1.0, 2.0]]
+    );
+}
+
+#[test]
+fn test_to_text() {
+    let a: Matrix<f64, 2, 2> = Matrix::new([[2.0, 0.0], [0.0, 1.0]]);
+    let (_, explanation) = a.inverse_explained();
+    let expected = "Start:\n\
+                    [ 2 0 | 1 0 ]\n\
+                    [ 0 1 | 0 1 ]\n\
+                    \n\
+                    Pivot at row 1, column 1\n\
+                    \n\
+                    R1 <- (0.5) R1\n\
+                    [   1   0 | 0.5   0 ]\n\
+                    [   0   1 |   0   1 ]\n\
+                    \n\
+                    Pivot at row 2, column 2\n";
+    assert_eq!(explanation.to_text(), expected);
+}
@@ -0,0 +1,103 @@
+use matops::scalar::Interval;
+use matops::{Matrix, Vector};
+
+type I = Interval<f64>;
+
+fn point<const M: usize, const N: usize>(data: [[f64; N]; M]) -> Matrix<I, M, N> {
+    Matrix::new(data.map(|row| row.map(Interval::point)))
+}
+
+fn assert_encloses<const M: usize, const N: usize>(
+    enclosure: Matrix<I, M, N>,
+    exact: [[f64; N]; M],
+) {
+    for i in 0..M {
+        for j in 0..N {
+            assert!(
+                enclosure[i][j].contains(exact[i][j]),
+                "{} does not contain {} at [{}][{}]",
+                enclosure[i][j],
+                exact[i][j],
+                i,
+                j
+            );
+        }
+    }
+}
+
+#[test]
+fn test_inverse_encloses_exact_inverse() {
+    let a = point([[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]]);
+    let inv = a.inverse().unwrap();
+    assert_encloses(
+        inv,
+        [[-24.0, 18.0, 5.0], [20.0, -15.0, -4.0], [-5.0, 4.0, 1.0]],
+    );
+    for row in inv.data.iter() {
+        for entry in row {
+            assert!(entry.width() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_hilbert_inverse_enclosure() {
+    // The entries 1/3, 1/5 are not representable, so start from enclosures of them.
+    let h: Matrix<I, 3, 3> = Matrix::new(core::array::from_fn(|i| {
+        core::array::from_fn(|j| I::point(1.0) / I::point((i + j + 1) as f64))
+    }));
+    let inv = h.inverse().unwrap();
+    assert_encloses(
+        inv,
+        [
+            [9.0, -36.0, 30.0],
+            [-36.0, 192.0, -180.0],
+            [30.0, -180.0, 180.0],
+        ],
+    );
+}
+
+#[test]
+fn test_solve_encloses_every_point_system() {
+    // Every matrix within ±0.01 of [[4, 1], [1, 3]] must have its solution in the result.
+    let a: Matrix<I, 2, 2> = Matrix::new([
+        [I::new(3.99, 4.01), I::new(0.99, 1.01)],
+        [I::new(0.99, 1.01), I::new(2.99, 3.01)],
+    ]);
+    let b = Vector::new([I::point(1.0), I::point(2.0)]);
+    let x = a.solve(&b).unwrap();
+    for &(a00, a01, a11) in &[(4.0, 1.0, 3.0), (3.99, 1.01, 3.01), (4.01, 0.99, 2.99)] {
+        let exact = Matrix::new([[a00, a01], [a01, a11]])
+            .solve(&Vector::new([1.0, 2.0]))
+            .unwrap();
+        assert!(x.data[0].contains(exact.data[0]));
+        assert!(x.data[1].contains(exact.data[1]));
+    }
+}
+
+#[test]
+fn test_possibly_singular_has_no_inverse() {
+    let a: Matrix<I, 2, 2> = Matrix::new([
+        [I::new(0.9, 1.1), I::point(2.0)],
+        [I::point(2.0), I::new(3.9, 4.1)],
+    ]);
+    assert!(a.inverse().is_none());
+    assert!(a
+        .solve(&Vector::new([I::point(1.0), I::point(1.0)]))
+        .is_none());
+}
+
+#[test]
+fn test_determinant_with_straddling_pivot_is_entire() {
+    // Every entry of the first column straddles zero, so there is no pivot,
+    // yet the determinant can be anything in [-0.2, 0.2].
+    let a = Matrix::new([
+        [Interval::new(-0.1, 0.1), Interval::point(1.0)],
+        [Interval::new(-0.1, 0.1), Interval::point(1.0)],
+    ]);
+    let det = a.determinant();
+    assert_eq!(det, I::ENTIRE);
+    assert!(det.contains(0.0) && det.contains(0.15));
+    let b = point([[2.0, 1.0], [1.0, 3.0]]);
+    assert!(b.determinant().contains(5.0));
+}
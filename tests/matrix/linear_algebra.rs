@@ -1,4 +1,5 @@
 use super::*;
+use matops::matrix::linear_algebra::OverflowError;
 use matops::Matrix;
 
 #[test]
@@ -73,3 +74,49 @@ fn test_transpose_transpose() {
 }
 
 // Note: test_transpose_of_product is in multiplication.rs
+
+#[test]
+fn test_determinant_exact() {
+    let a: Matrix<i64, 3, 3> = Matrix::new([[6, 1, 1], [4, -2, 5], [2, 8, 7]]);
+    assert_eq!(a.determinant_exact(), Ok(-306));
+
+    // A zero leading entry forces a row swap.
+    let b: Matrix<i64, 3, 3> = Matrix::new([[0, 2, 1], [3, 1, 4], [1, 0, 2]]);
+    assert_eq!(b.determinant_exact(), Ok(-5));
+
+    let singular: Matrix<i32, 3, 3> = Matrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    assert_eq!(singular.determinant_exact(), Ok(0));
+}
+
+#[test]
+fn test_determinant_exact_matches_float() {
+    let a: Matrix<i64, 4, 4> =
+        Matrix::new([[2, -1, 0, 3], [1, 5, -2, 4], [0, 3, 7, -1], [6, 2, 1, 8]]);
+    let mut a_f64: Matrix<f64, 4, 4> = Matrix::zeros();
+    for i in 0..4 {
+        for j in 0..4 {
+            a_f64[i][j] = a[i][j] as f64;
+        }
+    }
+    let exact = a.determinant_exact().unwrap();
+    assert!((a_f64.determinant() - exact as f64).abs() < 1e-6);
+}
+
+#[test]
+fn test_determinant_exact_overflow() {
+    let big = i64::MAX / 2;
+    let a: Matrix<i64, 2, 2> = Matrix::new([[big, 1], [1, big]]);
+    match a.determinant_exact() {
+        Err(OverflowError) => {}
+        other => panic!("expected overflow, got {:?}", other),
+    }
+    assert_eq!(
+        OverflowError.to_string(),
+        "arithmetic overflow in exact computation"
+    );
+    let wide: Matrix<i128, 2, 2> = Matrix::new([[big as i128, 1], [1, big as i128]]);
+    assert_eq!(
+        wide.determinant_exact(),
+        Ok((big as i128) * (big as i128) - 1)
+    );
+}
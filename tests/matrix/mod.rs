@@ -1,28 +1,22 @@
 pub mod arithmetic;
+pub mod blocks;
+pub mod camera;
+pub mod complex;
 pub mod creation;
+pub mod dual;
 pub mod edge_cases;
+pub mod elementary;
+pub mod explain;
 pub mod indexing;
+pub mod interval;
 pub mod linear_algebra;
+pub mod modp;
 pub mod multiplication;
+pub mod power;
+pub mod products;
+pub mod rational;
+pub mod rotation;
+pub mod semiring;
+pub mod transform;
 
-use matops::Matrix;
-
-pub fn assert_matrix_approx_eq<const M: usize, const N: usize>(
-    a: Matrix<f64, M, N>,
-    b: Matrix<f64, M, N>,
-    tolerance: f64,
-) {
-    for i in 0..M {
-        for j in 0..N {
-            assert!(
-                (a[i][j] - b[i][j]).abs() < tolerance,
-                "Matrices differ at [{}][{}]: {} != {} (difference: {})",
-                i,
-                j,
-                a[i][j],
-                b[i][j],
-                (a[i][j] - b[i][j]).abs()
-            );
-        }
-    }
-}
+pub use crate::common::assert_matrix_approx_eq;
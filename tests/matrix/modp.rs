@@ -0,0 +1,76 @@
+use matops::scalar::ModP;
+use matops::{Matrix, Vector};
+
+type F7 = ModP<7>;
+type F2 = ModP<2>;
+
+fn gf<const P: u64, const M: usize, const N: usize>(data: [[i64; N]; M]) -> Matrix<ModP<P>, M, N> {
+    let mut result = Matrix::zeros();
+    for i in 0..M {
+        for j in 0..N {
+            result[i][j] = ModP::from(data[i][j]);
+        }
+    }
+    result
+}
+
+#[test]
+fn test_from_negative_with_modulus_above_i64_max() {
+    // The largest prime below 2^64.
+    const P: u64 = 18_446_744_073_709_551_557;
+    assert_eq!(ModP::<P>::from(-1).value(), P - 1);
+    assert_eq!(ModP::<P>::from(i64::MIN).value(), P - (1 << 63));
+    assert_eq!(ModP::<P>::from(i64::MAX).value(), i64::MAX as u64);
+    assert_eq!(ModP::<P>::from(-1) + ModP::from(1), ModP::default());
+}
+
+#[test]
+fn test_arithmetic_wraps() {
+    let a: Matrix<F7, 2, 2> = gf([[3, 4], [5, 6]]);
+    let b: Matrix<F7, 2, 2> = gf([[6, 5], [4, 3]]);
+    assert_eq!(a + b, gf([[2, 2], [2, 2]]));
+    assert_eq!(a - b, gf([[4, 6], [1, 3]]));
+    assert_eq!(a * b, gf([[6, 6], [5, 1]]));
+    assert_eq!(-a, gf([[4, 3], [2, 1]]));
+}
+
+#[test]
+fn test_determinant_and_inverse() {
+    let a: Matrix<F7, 3, 3> = gf([[6, 1, 1], [4, -2, 5], [2, 8, 7]]);
+    // Over the integers det = -306, and -306 mod 7 = 2.
+    assert_eq!(a.determinant(), F7::new(2));
+    let inv = a.inverse().unwrap();
+    assert_eq!(a * inv, Matrix::identity());
+    assert_eq!(inv * a, Matrix::identity());
+}
+
+#[test]
+fn test_singular_modulo_p() {
+    // Invertible over the rationals (det = 7) but singular modulo 7.
+    let a: Matrix<F7, 2, 2> = gf([[3, 1], [1, 5]]);
+    assert_eq!(a.determinant(), F7::new(0));
+    assert!(a.inverse().is_none());
+    assert_eq!(a.rank(), 1);
+}
+
+#[test]
+fn test_hamming_parity_check_rank_and_rref() {
+    let h: Matrix<F2, 3, 7> = gf([
+        [1, 0, 1, 0, 1, 0, 1],
+        [0, 1, 1, 0, 0, 1, 1],
+        [0, 0, 0, 1, 1, 1, 1],
+    ]);
+    assert_eq!(h.rank(), 3);
+    assert_eq!(h.rref(), h);
+    // Every codeword of the [7, 4] Hamming code has zero syndrome.
+    let codeword: Vector<F2, 7> = Vector::new([1, 1, 1, 0, 0, 0, 0].map(F2::new));
+    assert_eq!(h * codeword, Vector::new([F2::new(0); 3]));
+}
+
+#[test]
+fn test_solve() {
+    let a: Matrix<ModP<101>, 3, 3> = gf([[2, 1, -1], [-3, -1, 2], [-2, 1, 2]]);
+    let b = Vector::new([8, -11, -3].map(ModP::from));
+    let x = a.solve(&b).unwrap();
+    assert_eq!(x, Vector::new([2, 3, -1].map(ModP::from)));
+}
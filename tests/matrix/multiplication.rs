@@ -10,6 +10,7 @@ fn test_matrix_associativity() {
 }
 
 #[test]
+#[allow(clippy::useless_vec)]
 fn test_product_of_diagonal_matrices() {
     let i = vec![1, 2, 3];
     let j = vec![4, 5, 6];
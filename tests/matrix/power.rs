@@ -0,0 +1,39 @@
+use super::*;
+use matops::matrix::linear_algebra::SingularMatrixError;
+use matops::Matrix;
+
+#[test]
+fn test_pow_counts_paths() {
+    // Adjacency matrix of the directed cycle 0 -> 1 -> 2 -> 0 plus the edge 0 -> 2.
+    let a: Matrix<i64, 3, 3> = Matrix::new([[0, 1, 1], [0, 0, 1], [1, 0, 0]]);
+    let a3 = a * a * a;
+    assert_eq!(a.pow(3), a3);
+    assert_eq!(a.pow(1), a);
+}
+
+#[test]
+fn test_pow_fibonacci() {
+    let fib: Matrix<u64, 2, 2> = Matrix::new([[1, 1], [1, 0]]);
+    // F(90) fits comfortably in a u64.
+    assert_eq!(fib.pow(90)[0][1], 2_880_067_194_370_816_120);
+}
+
+#[test]
+fn test_powi_negative() {
+    let a: Matrix<f64, 2, 2> = Matrix::new([[4.0, 7.0], [2.0, 6.0]]);
+    let a_inv = a.inverse().unwrap();
+    assert_matrix_approx_eq(a.powi(-1).unwrap(), a_inv, 1e-9);
+    assert_matrix_approx_eq(a.powi(-3).unwrap(), a_inv * a_inv * a_inv, 1e-9);
+    assert_matrix_approx_eq(
+        a.powi(3).unwrap() * a.powi(-3).unwrap(),
+        Matrix::identity(),
+        1e-9,
+    );
+}
+
+#[test]
+fn test_powi_zero_of_singular() {
+    let a: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    assert_matrix_approx_eq(a.powi(0).unwrap(), Matrix::identity(), 1e-9);
+    assert_eq!(a.powi(-2), Err(SingularMatrixError));
+}
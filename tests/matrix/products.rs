@@ -0,0 +1,52 @@
+use super::*;
+use matops::{Matrix, Vector};
+
+#[test]
+fn test_trace() {
+    let a: Matrix<f64, 3, 3> = Matrix::new([[6.0, 1.0, 1.0], [4.0, -2.0, 5.0], [2.0, 8.0, 7.0]]);
+    assert!((a.trace() - 11.0).abs() < 1e-9);
+
+    // tr(AB) = tr(BA)
+    let b: Matrix<i32, 2, 3> = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let c: Matrix<i32, 3, 2> = Matrix::new([[7, 8], [9, 10], [11, 12]]);
+    assert_eq!((b * c).trace(), (c * b).trace());
+}
+
+#[test]
+fn test_kronecker_with_identity() {
+    let a: Matrix<i32, 2, 2> = Matrix::new([[1, 2], [3, 4]]);
+    let i: Matrix<i32, 2, 2> = Matrix::identity();
+    let k: Matrix<i32, 4, 4> = i.kronecker(&a);
+    let expected = Matrix::new([[1, 2, 0, 0], [3, 4, 0, 0], [0, 0, 1, 2], [0, 0, 3, 4]]);
+    assert_eq!(k, expected);
+}
+
+#[test]
+fn test_kronecker_mixed_product() {
+    // (A ⊗ B)(C ⊗ D) = (AC) ⊗ (BD)
+    let a: Matrix<i32, 2, 2> = Matrix::new([[1, 2], [3, 4]]);
+    let b: Matrix<i32, 2, 2> = Matrix::new([[0, 5], [6, 7]]);
+    let c: Matrix<i32, 2, 2> = Matrix::new([[2, 0], [1, 1]]);
+    let d: Matrix<i32, 2, 2> = Matrix::new([[1, -1], [0, 3]]);
+    let ab: Matrix<i32, 4, 4> = a.kronecker(&b);
+    let cd: Matrix<i32, 4, 4> = c.kronecker(&d);
+    let expected: Matrix<i32, 4, 4> = (a * c).kronecker(&(b * d));
+    assert_eq!(ab * cd, expected);
+}
+
+#[test]
+fn test_outer_product() {
+    let u: Vector<i32, 2> = Vector::new([1, 2]);
+    let v: Vector<i32, 3> = Vector::new([3, 4, 5]);
+    let w: Vector<i32, 3> = Vector::new([1, 0, -1]);
+    // (u vᵀ) w = u (v · w)
+    assert_eq!(u.outer(&v) * w, u * v.dot(&w));
+}
+
+#[test]
+fn test_hadamard() {
+    let a: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    let b: Matrix<f64, 2, 2> = Matrix::new([[2.0, 0.5], [-1.0, 0.25]]);
+    assert_matrix_approx_eq(a.hadamard(&b), Matrix::new([[2.0, 1.0], [-3.0, 1.0]]), 1e-9);
+    assert_matrix_approx_eq(a.hadamard(&b).hadamard_div(&b), a, 1e-9);
+}
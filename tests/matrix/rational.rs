@@ -0,0 +1,110 @@
+use matops::scalar::Rational;
+use matops::{Matrix, Vector};
+
+fn r(num: i128, den: i128) -> Rational {
+    Rational::new(num, den)
+}
+
+fn int_matrix<const M: usize, const N: usize>(data: [[i64; N]; M]) -> Matrix<Rational, M, N> {
+    let mut result = Matrix::zeros();
+    for i in 0..M {
+        for j in 0..N {
+            result[i][j] = Rational::from(data[i][j]);
+        }
+    }
+    result
+}
+
+#[test]
+fn test_exact_determinant() {
+    let a = int_matrix([[6, 1, 1], [4, -2, 5], [2, 8, 7]]);
+    assert_eq!(a.determinant(), Rational::from(-306));
+
+    // The Hilbert matrix is the classic case where floating point drifts.
+    let h: Matrix<Rational, 4, 4> = Matrix::new(core::array::from_fn(|i| {
+        core::array::from_fn(|j| r(1, (i + j + 1) as i128))
+    }));
+    assert_eq!(h.determinant(), r(1, 6_048_000));
+}
+
+#[test]
+fn test_exact_inverse() {
+    let a = int_matrix([[4, 7], [2, 6]]);
+    let expected = Matrix::new([[r(3, 5), r(-7, 10)], [r(-1, 5), r(2, 5)]]);
+    let inv = a.inverse().unwrap();
+    assert_eq!(inv, expected);
+    assert_eq!(a * inv, Matrix::identity());
+}
+
+#[test]
+fn test_exact_singular() {
+    let a = int_matrix([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    assert_eq!(a.determinant(), Rational::ZERO);
+    assert!(a.inverse().is_none());
+    assert_eq!(a.rank(), 2);
+    assert_eq!(
+        a.rref(),
+        Matrix::new([
+            [r(1, 1), r(0, 1), r(-1, 1)],
+            [r(0, 1), r(1, 1), r(2, 1)],
+            [r(0, 1), r(0, 1), r(0, 1)],
+        ])
+    );
+}
+
+#[test]
+fn test_exact_solve() {
+    let a = int_matrix([[2, 1, -1], [-3, -1, 2], [-2, 1, 2]]);
+    let b = Vector::new([r(8, 1), r(-11, 1), r(-3, 1)]);
+    let x = a.solve(&b).unwrap();
+    assert_eq!(x, Vector::new([r(2, 1), r(3, 1), r(-1, 1)]));
+    assert_eq!(a * x, b);
+
+    let singular = int_matrix([[1, 2], [2, 4]]);
+    assert!(singular.solve(&Vector::new([r(1, 1), r(2, 1)])).is_none());
+}
+
+#[test]
+fn test_rational_ordering_without_overflow() {
+    let big = i128::MAX;
+    assert!(r(big - 1, big) > r(big - 2, big - 1));
+    assert!(r(big - 2, big - 1) < r(big - 1, big));
+    assert!(r(-1, big) < Rational::ZERO);
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn test_big_determinant_and_inverse() {
+    use matops::scalar::rational::BigRational;
+
+    let a = int_matrix([[4, 7], [2, 6]]);
+    assert_eq!(a.determinant_big(), BigRational::from(Rational::from(10)));
+    let inv = a.inverse_big().unwrap();
+    assert_eq!(inv[0][1], BigRational::from(r(-7, 10)));
+    assert!(int_matrix([[1, 2], [2, 4]]).inverse_big().is_none());
+
+    let x = a.solve_big(&Vector::new([Rational::from(1), Rational::from(0)]));
+    assert_eq!(
+        x.unwrap(),
+        [BigRational::from(r(3, 5)), BigRational::from(r(-1, 5))]
+    );
+    let rref = int_matrix([[1, 2], [2, 4]]).rref_big();
+    assert_eq!(rref[0][1], BigRational::from(Rational::from(2)));
+    assert_eq!(rref[1][1], BigRational::from(Rational::ZERO));
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn test_big_past_i128() {
+    use matops::scalar::rational::BigRational;
+
+    let max = Rational::new(i128::MAX, 1);
+    let a = Matrix::new([[max, Rational::from(1)], [Rational::from(1), max]]);
+    let big_max = BigRational::from(max);
+    let det = &big_max * &big_max - BigRational::from(Rational::from(1));
+    assert_eq!(a.determinant_big(), det);
+
+    let inv = a.inverse_big().unwrap();
+    assert_eq!(inv[0][0], &big_max / &det);
+    assert_eq!(inv[0][1], -BigRational::from(Rational::from(1)) / &det);
+}
@@ -0,0 +1,190 @@
+use crate::common::assert_matrix_approx_eq;
+use matops::matrix::{EulerFrame, EulerOrder};
+use matops::scalar::{Angle, Deg, Rad};
+use matops::{Matrix, Vector};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+const ORDERS: [EulerOrder; 12] = [
+    EulerOrder::XYZ,
+    EulerOrder::XZY,
+    EulerOrder::YXZ,
+    EulerOrder::YZX,
+    EulerOrder::ZXY,
+    EulerOrder::ZYX,
+    EulerOrder::XYX,
+    EulerOrder::XZX,
+    EulerOrder::YXY,
+    EulerOrder::YZY,
+    EulerOrder::ZXZ,
+    EulerOrder::ZYZ,
+];
+
+const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+fn radians(angles: [f64; 3]) -> [Angle<f64>; 3] {
+    angles.map(Angle::from_radians)
+}
+
+fn is_proper(order: EulerOrder) -> bool {
+    let name = format!("{:?}", order);
+    name.as_bytes()[0] == name.as_bytes()[2]
+}
+
+#[test]
+fn test_euler_round_trip_all_sequences() {
+    for &order in ORDERS.iter() {
+        for &frame in FRAMES.iter() {
+            let middle = if is_proper(order) { 1.1 } else { -0.6 };
+            let angles: [f64; 3] = [0.4, middle, -2.3];
+            let r = Matrix::from_euler(radians(angles), order, frame);
+            assert!(r.is_rotation(1e-12));
+            let back = r.to_euler(order, frame);
+            for n in 0..3 {
+                assert!(
+                    (back[n].radians() - angles[n]).abs() < 1e-9,
+                    "{:?} {:?}: {:?} != {:?}",
+                    order,
+                    frame,
+                    back,
+                    angles
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_intrinsic_is_reversed_extrinsic() {
+    let angles = [0.3, -0.7, 1.9];
+    let intrinsic = Matrix::from_euler(radians(angles), EulerOrder::ZYX, EulerFrame::Intrinsic);
+    let extrinsic = Matrix::from_euler(
+        radians([1.9, -0.7, 0.3]),
+        EulerOrder::XYZ,
+        EulerFrame::Extrinsic,
+    );
+    assert_matrix_approx_eq(intrinsic, extrinsic, 1e-12);
+
+    // Intrinsic rotations act about the rotated body axes, extrinsic ones
+    // about the fixed world axes.
+    let quarter = Angle::from(Deg(90.0));
+    let zero = Angle::default();
+    let y = Vector::new([0.0, 1.0, 0.0]);
+    let body = Matrix::from_euler(
+        [quarter, quarter, zero],
+        EulerOrder::ZXY,
+        EulerFrame::Intrinsic,
+    );
+    assert!((body * y).distance(&Vector::new([0.0, 0.0, 1.0])) < 1e-12);
+    let world = Matrix::from_euler(
+        [quarter, quarter, zero],
+        EulerOrder::ZXY,
+        EulerFrame::Extrinsic,
+    );
+    assert!((world * y).distance(&Vector::new([-1.0, 0.0, 0.0])) < 1e-12);
+}
+
+#[test]
+fn test_gimbal_lock() {
+    for &order in ORDERS.iter() {
+        for &frame in FRAMES.iter() {
+            let locked = if is_proper(order) {
+                [0.0, PI]
+            } else {
+                [FRAC_PI_2, -FRAC_PI_2]
+            };
+            for &middle in locked.iter() {
+                let r = Matrix::from_euler(radians([0.8, middle, 0.5]), order, frame);
+                let angles = r.to_euler(order, frame);
+                assert!(
+                    (angles[1].radians() - middle).abs() < 1e-6,
+                    "{:?} {:?}: {:?}",
+                    order,
+                    frame,
+                    angles
+                );
+                let zeroed = match frame {
+                    EulerFrame::Intrinsic => angles[2],
+                    EulerFrame::Extrinsic => angles[0],
+                };
+                assert_eq!(zeroed, Angle::default());
+                assert_matrix_approx_eq(Matrix::from_euler(angles, order, frame), r, 1e-9);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_axis_angle_and_rotation_vector() {
+    let axis: Vector<f64, 3> = Vector::new([1.0, 2.0, -2.0]);
+    let r = Matrix::from_axis_angle(&axis, Rad(0.9)).unwrap();
+    let (unit, angle) = r.to_axis_angle();
+    assert!(unit.distance(&Vector::new([1.0 / 3.0, 2.0 / 3.0, -2.0 / 3.0])) < 1e-12);
+    assert!((angle.radians() - 0.9).abs() < 1e-12);
+    assert!(Matrix::from_axis_angle(&Vector::new([0.0; 3]), Rad(1.0)).is_err());
+
+    // Half turns are recovered without loss of precision.
+    let half_turn = Matrix::from_axis_angle(&axis, Rad(PI)).unwrap();
+    let (unit, angle) = half_turn.to_axis_angle();
+    assert!((angle.radians() - PI).abs() < 1e-12);
+    assert!((unit.dot(&axis).abs() - 3.0).abs() < 1e-12);
+
+    let v = Vector::new([0.2, -0.1, 0.4]);
+    let r = Matrix::from_rotation_vector(&v);
+    assert!(r.to_rotation_vector().distance(&v) < 1e-12);
+    assert_matrix_approx_eq(
+        r,
+        Matrix::from_axis_angle(&v, Rad(v.magnitude())).unwrap(),
+        1e-12,
+    );
+    assert_eq!(
+        Matrix::from_rotation_vector(&Vector::new([0.0; 3])),
+        Matrix::identity()
+    );
+    assert_eq!(
+        Matrix::<f64, 3, 3>::identity().to_rotation_vector(),
+        Vector::new([0.0; 3])
+    );
+}
+
+#[test]
+fn test_is_rotation() {
+    let r = Matrix::from_euler(
+        radians([0.1, 0.2, 0.3]),
+        EulerOrder::XYZ,
+        EulerFrame::Intrinsic,
+    );
+    assert!(r.is_rotation(1e-12));
+    assert!(
+        Matrix::<f64, 4, 4>::rotation_axis(&Vector::new([1.0, 1.0, 0.0]), Rad(2.0))
+            .unwrap()
+            .is_rotation(1e-12)
+    );
+    assert!(!(r * 1.01).is_rotation(1e-6));
+    let reflection = Matrix::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]]);
+    assert!(!reflection.is_rotation(1e-6));
+}
+
+#[test]
+fn test_reorthonormalize_drift() {
+    let mut r = Matrix::identity();
+    let step = Matrix::from_axis_angle(&Vector::new([0.3, -1.0, 0.7]), Rad(0.01)).unwrap();
+    let mut drifted = step;
+    for i in 0..3 {
+        for j in 0..3 {
+            drifted[i][j] += 1e-4 * ((i * 3 + j) as f64 - 4.0);
+        }
+    }
+    for _ in 0..100 {
+        r = drifted * r;
+    }
+    assert!(!r.is_rotation(1e-3));
+    let fixed = r.reorthonormalize().unwrap();
+    assert!(fixed.is_rotation(1e-12));
+    // Already orthonormal matrices are left unchanged.
+    let exact = step.reorthonormalize().unwrap();
+    assert_matrix_approx_eq(exact, step, 1e-12);
+
+    let reflection = Matrix::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]]);
+    assert!(reflection.reorthonormalize().is_none());
+    assert!(Matrix::<f64, 3, 3>::zeros().reorthonormalize().is_none());
+}
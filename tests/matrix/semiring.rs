@@ -0,0 +1,125 @@
+use matops::scalar::{BoolOr, MaxPlus, MinPlus};
+use matops::Matrix;
+
+const INF: f64 = f64::INFINITY;
+
+#[test]
+fn test_all_pairs_shortest_paths() {
+    // Weighted digraph on 4 nodes; INF means no edge.
+    let w: Matrix<MinPlus<f64>, 4, 4> = Matrix::new(
+        [
+            [0.0, 3.0, INF, 7.0],
+            [8.0, 0.0, 2.0, INF],
+            [5.0, INF, 0.0, 1.0],
+            [2.0, INF, INF, 0.0],
+        ]
+        .map(|row| row.map(MinPlus)),
+    );
+    let expected: Matrix<MinPlus<f64>, 4, 4> = Matrix::new(
+        [
+            [0.0, 3.0, 5.0, 6.0],
+            [5.0, 0.0, 2.0, 3.0],
+            [3.0, 6.0, 0.0, 1.0],
+            [2.0, 5.0, 7.0, 0.0],
+        ]
+        .map(|row| row.map(MinPlus)),
+    );
+    // Shortest paths use at most n - 1 edges.
+    assert_eq!(w.pow(3), expected);
+    assert_eq!(w.pow(3), w.pow(10));
+    assert_eq!(w.semiring_mul(&w).semiring_mul(&w), expected);
+}
+
+#[test]
+fn test_integer_shortest_paths_saturate() {
+    let no_edge = u32::MAX;
+    let w: Matrix<MinPlus<u32>, 3, 3> = Matrix::new(
+        [[0, 4, no_edge], [no_edge, 0, 1], [no_edge, no_edge, 0]].map(|row| row.map(MinPlus)),
+    );
+    let d = w.pow(2);
+    assert_eq!(d[0][2], MinPlus(5));
+    assert_eq!(d[2][0], MinPlus(no_edge));
+    assert_eq!(
+        Matrix::<MinPlus<u32>, 3, 3>::identity()[0][1],
+        MinPlus(no_edge)
+    );
+}
+
+#[test]
+fn test_longest_path_schedule() {
+    // Task durations along a DAG: critical path lengths via max-plus.
+    let neg = f64::NEG_INFINITY;
+    let w: Matrix<MaxPlus<f64>, 3, 3> = Matrix::new(
+        [[neg, 2.0, 5.0], [neg, neg, 4.0], [neg, neg, neg]].map(|row| row.map(MaxPlus)),
+    );
+    assert_eq!((w * w)[0][2], MaxPlus(6.0));
+    let reach = w + w * w;
+    assert_eq!(reach[0][2], MaxPlus(6.0));
+    assert_eq!(reach[1][0], MaxPlus(neg));
+}
+
+#[test]
+fn test_integer_max_plus_identity() {
+    let w: Matrix<MaxPlus<i32>, 2, 2> =
+        Matrix::new([[i32::MIN, 3], [5, i32::MIN]].map(|row| row.map(MaxPlus)));
+    let identity = Matrix::<MaxPlus<i32>, 2, 2>::identity();
+    assert_eq!(identity[0][0], MaxPlus(0));
+    assert_eq!(identity[0][1], MaxPlus(i32::MIN));
+    assert_eq!(w * identity, w);
+    assert_eq!(w.pow(1), w);
+    assert_eq!(w.pow(2)[0][0], MaxPlus(8));
+    assert_eq!(w.pow(2)[0][1], MaxPlus(i32::MIN));
+}
+
+#[test]
+fn test_transitive_closure() {
+    // 0 -> 1 -> 2, 3 isolated.
+    let a: Matrix<BoolOr, 4, 4> = Matrix::new(
+        [
+            [false, true, false, false],
+            [false, false, true, false],
+            [false, false, false, false],
+            [false, false, false, false],
+        ]
+        .map(|row| row.map(BoolOr)),
+    );
+    let closure = (Matrix::identity() + a).pow(3);
+    assert_eq!(closure[0][2], BoolOr(true));
+    assert_eq!(closure[2][0], BoolOr(false));
+    assert_eq!(closure[3][3], BoolOr(true));
+    assert_eq!(closure[0][3], BoolOr(false));
+}
+
+#[test]
+fn test_boolean_semiring_mul() {
+    let a: Matrix<BoolOr, 2, 3> =
+        Matrix::new([[true, false, false], [false, false, true]].map(|row| row.map(BoolOr)));
+    let b: Matrix<BoolOr, 3, 2> =
+        Matrix::new([[false, true], [true, true], [true, false]].map(|row| row.map(BoolOr)));
+    let expected = Matrix::new([[false, true], [true, false]].map(|row| row.map(BoolOr)));
+    assert_eq!(a.semiring_mul(&b), expected);
+    assert_eq!(a * b, expected);
+}
+
+#[test]
+fn test_unsigned_max_plus() {
+    // None is "no edge"; Some(0) is the identity.
+    let w: Matrix<MaxPlus<Option<u32>>, 3, 3> = Matrix::new(
+        [
+            [None, Some(2), Some(5)],
+            [None, None, Some(4)],
+            [None, None, None],
+        ]
+        .map(|row| row.map(MaxPlus)),
+    );
+    let identity = Matrix::<MaxPlus<Option<u32>>, 3, 3>::identity();
+    assert_eq!(identity[0][0], MaxPlus(Some(0)));
+    assert_eq!(identity[0][1], MaxPlus(None));
+    assert_eq!(w * identity, w);
+    assert_eq!((w * w)[0][2], MaxPlus(Some(6)));
+    assert_eq!((w * w)[1][0], MaxPlus(None));
+    assert_eq!(
+        MaxPlus(Some(u64::MAX)) * MaxPlus(Some(1u64)),
+        MaxPlus(Some(u64::MAX))
+    );
+}
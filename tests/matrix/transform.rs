@@ -0,0 +1,166 @@
+use crate::common::{assert_matrix_approx_eq, assert_vector_approx_eq};
+use matops::scalar::{Deg, Quaternion, Rad, Rational};
+use matops::{Affine, Matrix, Vector};
+use std::f64::consts::FRAC_PI_2;
+
+#[test]
+fn test_2d_builders() {
+    let p = Vector::new([1.0, 2.0]);
+    let r: Matrix<f64, 3, 3> = Matrix::rotation_2d(Rad(FRAC_PI_2));
+    assert_vector_approx_eq(
+        r.transform_point(&p).unwrap(),
+        Vector::new([-2.0, 1.0]),
+        1e-9,
+    );
+    // Agrees with `Vector::rotate`, which takes degrees.
+    assert_vector_approx_eq(r.transform_point(&p).unwrap(), p.rotate(Deg(90.0)), 1e-9);
+
+    let s: Matrix<f64, 3, 3> = Matrix::scaling(&Vector::new([2.0, -3.0]));
+    assert_eq!(s.transform_point(&p), Some(Vector::new([2.0, -6.0])));
+
+    let shear: Matrix<f64, 3, 3> = Matrix::shear(0, 1, 0.5);
+    assert_eq!(shear.transform_point(&p), Some(Vector::new([2.0, 2.0])));
+
+    let mirror: Matrix<f64, 3, 3> = Matrix::reflection(&Vector::new([1.0, 1.0])).unwrap();
+    assert_vector_approx_eq(
+        mirror.transform_point(&p).unwrap(),
+        Vector::new([-2.0, -1.0]),
+        1e-9,
+    );
+    assert!(Matrix::<f64, 3, 3>::reflection(&Vector::new([0.0, 0.0])).is_err());
+
+    // Build once, reuse: rotate about (1, 1) by conjugating with translations.
+    let about: Matrix<f64, 3, 3> = Matrix::translation(&Vector::new([1.0, 1.0]))
+        * r
+        * Matrix::translation(&Vector::new([-1.0, -1.0]));
+    assert_vector_approx_eq(
+        about.transform_point(&Vector::new([2.0, 1.0])).unwrap(),
+        Vector::new([1.0, 2.0]),
+        1e-9,
+    );
+    assert_vector_approx_eq(
+        about.transform_point(&Vector::new([1.0, 1.0])).unwrap(),
+        Vector::new([1.0, 1.0]),
+        1e-9,
+    );
+}
+
+#[test]
+#[should_panic(expected = "shear axes must differ")]
+fn test_shear_same_axis_panics() {
+    Matrix::<f64, 4, 4>::shear(1, 1, 2.0);
+}
+
+#[test]
+fn test_3d_rotations() {
+    let x = Vector::new([1.0, 0.0, 0.0]);
+    let y = Vector::new([0.0, 1.0, 0.0]);
+    let z = Vector::new([0.0, 0.0, 1.0]);
+    let quarter = FRAC_PI_2;
+    assert_vector_approx_eq(
+        Matrix::rotation_x(Rad(quarter)).transform_vector(&y),
+        z,
+        1e-9,
+    );
+    assert_vector_approx_eq(
+        Matrix::rotation_y(Rad(quarter)).transform_vector(&z),
+        x,
+        1e-9,
+    );
+    assert_vector_approx_eq(
+        Matrix::rotation_z(Rad(quarter)).transform_vector(&x),
+        y,
+        1e-9,
+    );
+
+    assert_matrix_approx_eq(
+        Matrix::rotation_axis(&(z * 3.0), Rad(0.4)).unwrap(),
+        Matrix::rotation_z(Rad(0.4)),
+        1e-12,
+    );
+    let axis = Vector::new([1.0, -2.0, 0.5]);
+    let q = Quaternion::from_axis_angle(&axis, Rad(1.3)).unwrap();
+    let m = Matrix::rotation_axis(&axis, Rad(1.3)).unwrap();
+    let v = Vector::new([0.3, 0.7, -1.1]);
+    assert_vector_approx_eq(m.transform_vector(&v), q.rotate(&v), 1e-9);
+    assert!(Matrix::<f64, 4, 4>::rotation_axis(&Vector::new([0.0; 3]), Rad(1.0)).is_err());
+}
+
+#[test]
+fn test_points_and_vectors() {
+    let t: Matrix<f64, 4, 4> = Matrix::translation(&Vector::new([1.0, 2.0, 3.0]));
+    let p = Vector::new([1.0, 1.0, 1.0]);
+    assert_eq!(t.transform_point(&p), Some(Vector::new([2.0, 3.0, 4.0])));
+    assert_eq!(t.transform_vector(&p), p);
+
+    // A projective bottom row is divided out.
+    let mut projective = Matrix::<f64, 4, 4>::identity();
+    projective[3][3] = 2.0;
+    assert_eq!(
+        projective.transform_point(&p),
+        Some(Vector::new([0.5, 0.5, 0.5]))
+    );
+}
+
+#[test]
+fn test_point_at_infinity() {
+    // The bottom row sends x = 1 to w = 0.
+    let mut projective = Matrix::<Rational, 3, 3>::identity();
+    projective[2].copy_from_slice(&[Rational::ONE, Rational::ZERO, -Rational::ONE]);
+    let at_infinity = Vector::new([Rational::from(1), Rational::from(5)]);
+    assert_eq!(projective.transform_point(&at_infinity), None);
+    let finite = Vector::new([Rational::from(3), Rational::from(4)]);
+    assert_eq!(
+        projective.transform_point(&finite),
+        Some(Vector::new([Rational::new(3, 2), Rational::from(2)]))
+    );
+
+    let mut float = Matrix::<f64, 4, 4>::identity();
+    float[3].copy_from_slice(&[0.0, 0.0, 1.0, 0.0]);
+    assert_eq!(float.transform_point(&Vector::new([1.0, 2.0, 0.0])), None);
+}
+
+#[test]
+fn test_affine_composition_and_inverse() {
+    let a = Affine::<f64, 3>::from_homogeneous(
+        &(Matrix::translation(&Vector::new([1.0, -2.0, 0.5])) * Matrix::rotation_x(Rad(0.3))),
+    );
+    let b = Affine::<f64, 3>::from_homogeneous(&Matrix::<f64, 4, 4>::scaling(&Vector::new([
+        2.0, 1.0, 0.5,
+    ])));
+    let p = Vector::new([0.2, -0.4, 1.0]);
+    assert_vector_approx_eq(
+        (a * b).transform_point(&p),
+        a.transform_point(&b.transform_point(&p)),
+        1e-9,
+    );
+    let h: Matrix<f64, 4, 4> = (a * b).to_homogeneous();
+    assert_matrix_approx_eq(h, a.to_homogeneous() * b.to_homogeneous(), 1e-12);
+
+    let inv = (a * b).inverse().unwrap();
+    assert_vector_approx_eq(inv.transform_point(&(a * b).transform_point(&p)), p, 1e-9);
+    let h_inv: Matrix<f64, 4, 4> = inv.to_homogeneous();
+    assert_eq!(h_inv[3], [0.0, 0.0, 0.0, 1.0]);
+
+    assert_eq!(a.rigid_inverse().linear, a.linear.transpose());
+    assert_vector_approx_eq(
+        a.rigid_inverse().transform_point(&a.transform_point(&p)),
+        p,
+        1e-9,
+    );
+
+    let singular = Affine::new(Matrix::zeros(), Vector::new([1.0, 0.0]));
+    assert!(singular.inverse().is_none());
+}
+
+#[test]
+fn test_affine_inverse_is_exact_over_rationals() {
+    let r = |n: i128, d: i128| Rational::new(n, d);
+    let a = Affine::new(
+        Matrix::new([[r(1, 3), r(2, 1)], [r(0, 1), r(5, 7)]]),
+        Vector::new([r(1, 2), r(-4, 9)]),
+    );
+    let inv = a.inverse().unwrap();
+    assert_eq!(inv * a, Affine::identity());
+    assert_eq!(a * inv, Affine::identity());
+}
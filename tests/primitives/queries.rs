@@ -0,0 +1,264 @@
+use crate::common::assert_vector_approx_eq;
+use matops::primitives::{Aabb, Line, Plane, Ray, Segment, Sphere, Triangle};
+use matops::Vector;
+
+#[test]
+fn test_line_ray_segment_closest_points() {
+    let p = Vector::new([1.0, 2.0, 3.0, 4.0]);
+    let line = Line::new(Vector::new([0.0; 4]), Vector::new([0.0, 0.0, 0.0, 2.0]));
+    assert_vector_approx_eq(
+        line.closest_point(&p),
+        Vector::new([0.0, 0.0, 0.0, 4.0]),
+        1e-12,
+    );
+    assert!((line.distance(&p) - 14f64.sqrt()).abs() < 1e-12);
+
+    let ray = Ray::new(Vector::new([0.0, 0.0]), Vector::new([1.0, 1.0]));
+    assert_vector_approx_eq(
+        ray.closest_point(&Vector::new([2.0, 0.0])),
+        Vector::new([1.0, 1.0]),
+        1e-12,
+    );
+    assert_eq!(ray.distance(&Vector::new([-3.0, -4.0])), 5.0);
+
+    let segment = Segment::new(Vector::new([0.0, 0.0]), Vector::new([4.0, 0.0]));
+    assert_eq!(segment.length(), 4.0);
+    assert_eq!(
+        segment.closest_point(&Vector::new([6.0, 1.0])),
+        Vector::new([4.0, 0.0])
+    );
+    assert_eq!(
+        segment.closest_point(&Vector::new([1.0, 1.0])),
+        Vector::new([1.0, 0.0])
+    );
+    assert_eq!(segment.distance(&Vector::new([-3.0, 4.0])), 5.0);
+}
+
+#[test]
+fn test_segment_segment() {
+    // Skew segments in 3D.
+    let a = Segment::new(Vector::new([0.0, 0.0, 0.0]), Vector::new([2.0, 0.0, 0.0]));
+    let b = Segment::new(Vector::new([1.0, -1.0, 1.0]), Vector::new([1.0, 1.0, 1.0]));
+    let (p, q) = a.closest_points(&b);
+    assert_vector_approx_eq(p, Vector::new([1.0, 0.0, 0.0]), 1e-12);
+    assert_vector_approx_eq(q, Vector::new([1.0, 0.0, 1.0]), 1e-12);
+    assert_eq!(a.distance_to_segment(&b), 1.0);
+    assert_eq!(a.intersect(&b), None);
+
+    // Closest points clamped to endpoints.
+    let c = Segment::new(Vector::new([3.0, 1.0, 0.0]), Vector::new([5.0, 1.0, 0.0]));
+    let (p, q) = a.closest_points(&c);
+    assert_vector_approx_eq(p, Vector::new([2.0, 0.0, 0.0]), 1e-12);
+    assert_vector_approx_eq(q, Vector::new([3.0, 1.0, 0.0]), 1e-12);
+
+    // Parallel, overlapping and degenerate cases.
+    let parallel = Segment::new(Vector::new([0.5, 2.0, 0.0]), Vector::new([1.5, 2.0, 0.0]));
+    assert_eq!(a.distance_to_segment(&parallel), 2.0);
+    let overlapping = Segment::new(Vector::new([1.0, 0.0, 0.0]), Vector::new([3.0, 0.0, 0.0]));
+    assert!(a.intersect(&overlapping).is_some());
+    let point = Segment::new(Vector::new([1.0, 0.0, 0.0]), Vector::new([1.0, 0.0, 0.0]));
+    assert_eq!(a.intersect(&point), Some(Vector::new([1.0, 0.0, 0.0])));
+    assert_eq!(point.distance_to_segment(&point), 0.0);
+
+    // Touching at an endpoint.
+    let touching = Segment::new(Vector::new([2.0, 0.0, 0.0]), Vector::new([2.0, 5.0, 0.0]));
+    assert_eq!(a.intersect(&touching), Some(Vector::new([2.0, 0.0, 0.0])));
+}
+
+#[test]
+fn test_small_scale_queries() {
+    let a = Segment::new(Vector::new([0.0, 0.0]), Vector::new([0.002, 0.002]));
+    let b = Segment::new(Vector::new([0.0, 0.002]), Vector::new([0.002, 0.0]));
+    assert_vector_approx_eq(a.intersect(&b).unwrap(), Vector::new([0.001, 0.001]), 1e-12);
+    assert!(a.distance_to_segment(&b) < 1e-15);
+
+    let triangle: Triangle<f64, 3> = Triangle::new(
+        Vector::new([0.0, 0.0, 0.0]),
+        Vector::new([1e-5, 0.0, 0.0]),
+        Vector::new([0.0, 1e-5, 0.0]),
+    );
+    let ray = Ray::new(
+        Vector::new([2e-6, 2e-6, 1.0]),
+        Vector::new([0.0, 0.0, -1.0]),
+    );
+    let t = ray.intersect_triangle(&triangle).unwrap();
+    assert!((t - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_plane() {
+    let plane = Plane::from_points(
+        &Vector::new([0.0, 0.0, 1.0]),
+        &Vector::new([1.0, 0.0, 1.0]),
+        &Vector::new([0.0, 1.0, 1.0]),
+    )
+    .unwrap();
+    assert_vector_approx_eq(plane.normal, Vector::new([0.0, 0.0, 1.0]), 1e-12);
+    assert_eq!(plane.offset, 1.0);
+    assert_eq!(plane.signed_distance(&Vector::new([3.0, 4.0, -2.0])), -3.0);
+    assert_eq!(
+        plane.closest_point(&Vector::new([3.0, 4.0, -2.0])),
+        Vector::new([3.0, 4.0, 1.0])
+    );
+    assert!(Plane::from_points(
+        &Vector::new([0.0, 0.0, 0.0]),
+        &Vector::new([1.0, 1.0, 1.0]),
+        &Vector::new([2.0, 2.0, 2.0])
+    )
+    .is_err());
+
+    // A line in 2D is a hyperplane too.
+    let line: Plane<f64, 2> = Plane::new(Vector::new([3.0, 4.0]), 10.0).unwrap();
+    assert!((line.signed_distance(&Vector::new([0.0, 0.0])) + 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_ray_plane() {
+    let plane = Plane::from_point_normal(
+        &Vector::new([0.0, 0.0, 5.0]),
+        &Vector::new([0.0, 0.0, -1.0]),
+    )
+    .unwrap();
+    let ray = Ray::new(Vector::new([1.0, 1.0, 0.0]), Vector::new([1.0, 0.0, 1.0]));
+    let t = ray.intersect_plane(&plane).unwrap();
+    assert_vector_approx_eq(ray.point_at(t), Vector::new([6.0, 1.0, 5.0]), 1e-12);
+    // Hits from behind the plane count too; parallel rays and rays pointing
+    // away miss.
+    let behind = Ray::new(Vector::new([0.0, 0.0, 9.0]), Vector::new([0.0, 0.0, -1.0]));
+    assert_eq!(behind.intersect_plane(&plane), Some(4.0));
+    let parallel = Ray::new(Vector::new([0.0, 0.0, 0.0]), Vector::new([1.0, 0.0, 0.0]));
+    assert_eq!(parallel.intersect_plane(&plane), None);
+    let away = Ray::new(Vector::new([0.0, 0.0, 0.0]), Vector::new([0.0, 0.0, -1.0]));
+    assert_eq!(away.intersect_plane(&plane), None);
+}
+
+#[test]
+fn test_ray_triangle() {
+    let triangle: Triangle<f64, 3> = Triangle::new(
+        Vector::new([0.0, 0.0, 1.0]),
+        Vector::new([2.0, 0.0, 1.0]),
+        Vector::new([0.0, 2.0, 1.0]),
+    );
+    let down = Vector::new([0.0, 0.0, -1.0]);
+    let hit = Ray::new(Vector::new([0.5, 0.5, 3.0]), down);
+    assert_eq!(hit.intersect_triangle(&triangle), Some(2.0));
+    // Back faces are hit as well.
+    let up = Ray::new(Vector::new([0.5, 0.5, -1.0]), down * -1.0);
+    assert_eq!(up.intersect_triangle(&triangle), Some(2.0));
+    // Outside each edge.
+    for &origin in [[-0.1, 0.5, 3.0], [0.5, -0.1, 3.0], [1.1, 1.1, 3.0]].iter() {
+        assert_eq!(
+            Ray::new(Vector::new(origin), down).intersect_triangle(&triangle),
+            None
+        );
+    }
+    // Behind the ray and in the plane of the triangle.
+    let behind = Ray::new(Vector::new([0.5, 0.5, 0.0]), down);
+    assert_eq!(behind.intersect_triangle(&triangle), None);
+    let grazing = Ray::new(Vector::new([-1.0, 0.5, 1.0]), Vector::new([1.0, 0.0, 0.0]));
+    assert_eq!(grazing.intersect_triangle(&triangle), None);
+    // Agrees with the ray-plane query inside the triangle.
+    let slanted = Ray::new(
+        Vector::new([-1.0, -1.0, 4.0]),
+        Vector::new([0.5, 0.6, -1.0]),
+    );
+    let plane = Plane::from_points(&triangle.a, &triangle.b, &triangle.c).unwrap();
+    let t = slanted.intersect_triangle(&triangle).unwrap();
+    assert!((t - slanted.intersect_plane(&plane).unwrap()).abs() < 1e-12);
+}
+
+#[test]
+fn test_triangle_closest_point_in_3d() {
+    let triangle = Triangle::new(
+        Vector::new([0.0, 0.0, 0.0]),
+        Vector::new([1.0, 0.0, 0.0]),
+        Vector::new([0.0, 1.0, 0.0]),
+    );
+    assert_vector_approx_eq(
+        triangle.closest_point(&Vector::new([0.2, 0.3, 5.0])),
+        Vector::new([0.2, 0.3, 0.0]),
+        1e-12,
+    );
+    assert_vector_approx_eq(
+        triangle.closest_point(&Vector::new([1.0, 1.0, -2.0])),
+        Vector::new([0.5, 0.5, 0.0]),
+        1e-12,
+    );
+    assert_vector_approx_eq(
+        triangle.closest_point(&Vector::new([0.0, 3.0, 1.0])),
+        Vector::new([0.0, 1.0, 0.0]),
+        1e-12,
+    );
+    assert_eq!(triangle.distance(&Vector::new([0.2, 0.3, -5.0])), 5.0);
+}
+
+#[test]
+fn test_sphere() {
+    let sphere = Sphere::new(Vector::new([1.0, 1.0, 1.0]), 2.0);
+    assert!(sphere.contains(&Vector::new([2.0, 2.0, 2.0])));
+    assert!(!sphere.contains(&Vector::new([3.0, 3.0, 1.0])));
+    assert_eq!(sphere.signed_distance(&Vector::new([1.0, 1.0, 6.0])), 3.0);
+    assert_eq!(sphere.signed_distance(&Vector::new([1.0, 1.0, 1.0])), -2.0);
+    assert_eq!(
+        sphere.closest_point(&Vector::new([1.0, 1.0, 6.0])),
+        Vector::new([1.0, 1.0, 3.0])
+    );
+    assert_eq!(
+        sphere.closest_point(&sphere.center),
+        Vector::new([3.0, 1.0, 1.0])
+    );
+
+    let touching = Sphere::new(Vector::new([1.0, 4.0, 1.0]), 1.0);
+    assert!(sphere.intersects(&touching));
+    assert_eq!(sphere.penetration_depth(&touching), Some(0.0));
+    let disc = Sphere::new(Vector::new([0.0, 0.0]), 1.0);
+    assert!(!disc.intersects(&Sphere::new(Vector::new([2.0, 1.0]), 1.0)));
+}
+
+#[test]
+fn test_aabb() {
+    let points = [
+        Vector::new([1.0, 5.0, -1.0]),
+        Vector::new([-2.0, 3.0, 0.0]),
+        Vector::new([0.0, 4.0, 2.0]),
+    ];
+    let aabb = Aabb::from_points(&points).unwrap();
+    assert_eq!(aabb.min, Vector::new([-2.0, 3.0, -1.0]));
+    assert_eq!(aabb.max, Vector::new([1.0, 5.0, 2.0]));
+    assert_eq!(aabb.center(), Vector::new([-0.5, 4.0, 0.5]));
+    assert_eq!(aabb.size(), Vector::new([3.0, 2.0, 3.0]));
+    assert!(points.iter().all(|p| aabb.contains(p)));
+    assert!(Aabb::<f64, 3>::from_points(&[]).is_none());
+
+    assert_eq!(
+        aabb.closest_point(&Vector::new([5.0, 4.0, -3.0])),
+        Vector::new([1.0, 4.0, -1.0])
+    );
+    assert_eq!(aabb.signed_distance(&Vector::new([1.0, 9.0, 2.0])), 4.0);
+    assert_eq!(aabb.signed_distance(&Vector::new([-0.5, 4.0, 0.5])), -1.0);
+
+    let other = Aabb::new(Vector::new([1.0, 0.0, 0.0]), Vector::new([3.0, 3.0, 3.0]));
+    assert!(aabb.intersects(&other));
+    let apart = Aabb::new(Vector::new([1.5, 0.0, 0.0]), Vector::new([3.0, 3.0, 3.0]));
+    assert!(!aabb.intersects(&apart));
+}
+
+#[test]
+fn test_ray_aabb() {
+    let aabb = Aabb::new(Vector::new([1.0, 1.0, 1.0]), Vector::new([2.0, 3.0, 4.0]));
+    let ray = Ray::new(Vector::new([0.0, 0.0, 0.0]), Vector::new([1.0, 1.0, 1.0]));
+    assert_eq!(ray.intersect_aabb(&aabb), Some((1.0, 2.0)));
+    // Negative direction components swap the slab ends.
+    let back = Ray::new(Vector::new([3.0, 2.0, 2.0]), Vector::new([-1.0, 0.0, 0.0]));
+    assert_eq!(back.intersect_aabb(&aabb), Some((1.0, 2.0)));
+    // Starting inside enters at 0.
+    let inside = Ray::new(Vector::new([1.5, 2.0, 2.0]), Vector::new([0.0, 0.0, 1.0]));
+    assert_eq!(inside.intersect_aabb(&aabb), Some((0.0, 2.0)));
+    // Axis-parallel rays outside a slab, and boxes behind the ray, miss.
+    let outside = Ray::new(Vector::new([0.0, 5.0, 2.0]), Vector::new([1.0, 0.0, 0.0]));
+    assert_eq!(outside.intersect_aabb(&aabb), None);
+    let behind = Ray::new(Vector::new([5.0, 2.0, 2.0]), Vector::new([1.0, 0.0, 0.0]));
+    assert_eq!(behind.intersect_aabb(&aabb), None);
+    let skew = Ray::new(Vector::new([0.0, 0.0, 0.0]), Vector::new([1.0, 0.1, 1.0]));
+    assert_eq!(skew.intersect_aabb(&aabb), None);
+}
@@ -0,0 +1,180 @@
+use crate::common::assert_vector_approx_eq;
+use matops::primitives::{Simplex, Tetrahedron, Triangle};
+use matops::Vector;
+
+#[test]
+fn test_triangle_barycentric_2d() {
+    let t: Triangle<f64, 2> = Triangle::new(
+        Vector::new([0.0, 0.0]),
+        Vector::new([4.0, 0.0]),
+        Vector::new([0.0, 2.0]),
+    );
+    let w = t.barycentric(&Vector::new([1.0, 1.0])).unwrap();
+    let expected = [0.25, 0.25, 0.5];
+    for i in 0..3 {
+        assert!((w[i] - expected[i]).abs() < 1e-12);
+    }
+    assert!(t.contains(&Vector::new([1.0, 1.0])));
+    assert!(t.contains(&Vector::new([2.0, 0.0])));
+    assert!(t.contains(&Vector::new([0.0, 2.0])));
+    assert!(!t.contains(&Vector::new([3.0, 1.0])));
+    assert!(!t.contains(&Vector::new([-0.1, 1.0])));
+    assert_eq!(t.signed_area(), 4.0);
+    assert_eq!(Triangle::new(t.a, t.c, t.b).signed_area(), -4.0);
+}
+
+#[test]
+fn test_triangle_barycentric_3d() {
+    let t = Triangle::new(
+        Vector::new([0.0, 0.0, 1.0]),
+        Vector::new([2.0, 0.0, 1.0]),
+        Vector::new([0.0, 2.0, 1.0]),
+    );
+    let above = Vector::new([0.5, 0.5, 3.0]);
+    let w = t.barycentric(&above).unwrap();
+    assert_vector_approx_eq(t.point_at(w), Vector::new([0.5, 0.5, 1.0]), 1e-12);
+    assert!(!t.contains(&above));
+    assert!(t.contains(&Vector::new([0.5, 0.5, 1.0])));
+
+    let attribute = Vector::new([w[0] * 10.0 + w[1] * 20.0 + w[2] * 30.0]);
+    assert_vector_approx_eq(attribute, Vector::new([17.5]), 1e-12);
+
+    let degenerate = Triangle::new(t.a, t.b, t.a * 0.5 + t.b * 0.5);
+    assert_eq!(degenerate.barycentric(&above), None);
+    assert!(!degenerate.contains(&t.a));
+}
+
+#[test]
+fn test_triangle_area_and_normal() {
+    let t: Triangle<f64, 3> = Triangle::new(
+        Vector::new([0.0, 0.0, 0.0]),
+        Vector::new([3.0, 0.0, 0.0]),
+        Vector::new([0.0, 4.0, 0.0]),
+    );
+    assert!((t.area() - 6.0).abs() < 1e-12);
+    assert_eq!(t.normal().unwrap(), Vector::new([0.0, 0.0, 1.0]));
+    assert_eq!(
+        Triangle::new(t.a, t.c, t.b).normal().unwrap(),
+        Vector::new([0.0, 0.0, -1.0])
+    );
+    assert!(Triangle::new(t.a, t.b, t.b * 2.0).normal().is_err());
+
+    let skew: Triangle<f64, 4> = Triangle::new(
+        Vector::new([0.0, 0.0, 0.0, 0.0]),
+        Vector::new([1.0, 1.0, 0.0, 0.0]),
+        Vector::new([0.0, 0.0, 1.0, 1.0]),
+    );
+    assert!((skew.area() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_triangle_centers() {
+    let right = Triangle::new(
+        Vector::new([0.0, 0.0]),
+        Vector::new([3.0, 0.0]),
+        Vector::new([0.0, 4.0]),
+    );
+    // The circumcenter of a right triangle is the midpoint of its hypotenuse.
+    assert_vector_approx_eq(
+        right.circumcenter().unwrap(),
+        Vector::new([1.5, 2.0]),
+        1e-12,
+    );
+    // Inradius (a + b - c) / 2 = 1.
+    assert_vector_approx_eq(right.incenter().unwrap(), Vector::new([1.0, 1.0]), 1e-12);
+
+    let t = Triangle::new(
+        Vector::new([1.0, 0.0, 0.0]),
+        Vector::new([0.0, 1.0, 0.0]),
+        Vector::new([0.0, 0.0, 1.0]),
+    );
+    let third = Vector::new([1.0 / 3.0; 3]);
+    assert_vector_approx_eq(t.circumcenter().unwrap(), third, 1e-12);
+    assert_vector_approx_eq(t.incenter().unwrap(), third, 1e-12);
+
+    let flat = Triangle::new(right.a, right.b, right.b * 2.0);
+    assert_eq!(flat.circumcenter(), None);
+    assert_eq!(flat.incenter(), None);
+}
+
+#[test]
+fn test_tetrahedron_volume() {
+    let t: Tetrahedron<f64> = Simplex::new([
+        Vector::new([1.0, 1.0, 1.0]),
+        Vector::new([3.0, 1.0, 1.0]),
+        Vector::new([1.0, 4.0, 1.0]),
+        Vector::new([1.0, 1.0, 5.0]),
+    ]);
+    assert!((t.signed_volume() - 4.0).abs() < 1e-12);
+    let [a, b, c, d] = t.vertices;
+    let mirrored = Simplex::new([a, c, b, d]);
+    assert!((mirrored.signed_volume() + 4.0).abs() < 1e-12);
+    assert!((mirrored.volume() - 4.0).abs() < 1e-12);
+
+    let flat = Simplex::new([a, b, c, a * 0.5 + b * 0.5]);
+    assert_eq!(flat.volume(), 0.0);
+    assert_eq!(flat.barycentric(&a), None);
+}
+
+#[test]
+fn test_simplex_barycentric() {
+    let triangle = Simplex::new([
+        Vector::new([0.0, 0.0]),
+        Vector::new([4.0, 0.0]),
+        Vector::new([0.0, 2.0]),
+    ]);
+    assert_eq!(triangle.signed_volume(), 4.0);
+    let w = triangle.barycentric(&Vector::new([1.0, 1.0])).unwrap();
+    assert_eq!(w, [0.25, 0.25, 0.5]);
+
+    let pentatope: Simplex<f64, 4, 5> = Simplex::new([
+        Vector::new([0.0; 4]),
+        Vector::new([1.0, 0.0, 0.0, 0.0]),
+        Vector::new([0.0, 1.0, 0.0, 0.0]),
+        Vector::new([0.0, 0.0, 1.0, 0.0]),
+        Vector::new([0.0, 0.0, 0.0, 1.0]),
+    ]);
+    assert!((pentatope.volume() - 1.0 / 24.0).abs() < 1e-15);
+    let p = Vector::new([0.1, 0.2, 0.3, 0.1]);
+    let w = pentatope.barycentric(&p).unwrap();
+    assert!((w.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    assert_vector_approx_eq(pentatope.point_at(w), p, 1e-12);
+    assert!(pentatope.contains(&p));
+    assert!(pentatope.contains(&pentatope.vertices[3]));
+    assert!(!pentatope.contains(&Vector::new([0.3, 0.3, 0.3, 0.3])));
+}
+
+#[test]
+fn test_small_scale() {
+    let h = 1e-5;
+    let triangle: Triangle<f64, 3> = Triangle::new(
+        Vector::new([1.0, 1.0, 0.0]),
+        Vector::new([1.0 + h, 1.0, 0.0]),
+        Vector::new([1.0, 1.0 + h, 0.0]),
+    );
+    let p = triangle.point_at([0.2, 0.3, 0.5]);
+    let w = triangle.barycentric(&p).unwrap();
+    assert!((w[1] - 0.3).abs() < 1e-9 && (w[2] - 0.5).abs() < 1e-9);
+    assert!(triangle.contains(&p));
+    assert!(!triangle.contains(&triangle.point_at([-0.1, 0.6, 0.5])));
+    assert!(!triangle.contains(&(p + Vector::new([0.0, 0.0, h]))));
+    assert_vector_approx_eq(
+        triangle.circumcenter().unwrap(),
+        Vector::new([1.0 + h / 2.0, 1.0 + h / 2.0, 0.0]),
+        1e-12,
+    );
+    assert!(triangle.incenter().is_some());
+
+    let tetrahedron: Tetrahedron<f64> = Simplex::new([
+        Vector::new([0.0, 0.0, 0.0]),
+        Vector::new([h, 0.0, 0.0]),
+        Vector::new([0.0, h, 0.0]),
+        Vector::new([0.0, 0.0, h]),
+    ]);
+    let expected = h * h * h / 6.0;
+    assert!((tetrahedron.signed_volume() - expected).abs() < expected * 1e-9);
+    let q = Vector::new([h / 4.0; 3]);
+    let w = tetrahedron.barycentric(&q).unwrap();
+    assert!(w.iter().all(|&x| (x - 0.25).abs() < 1e-9));
+    assert!(tetrahedron.contains(&q));
+}
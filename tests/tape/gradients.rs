@@ -0,0 +1,135 @@
+use crate::common::assert_matrix_approx_eq;
+use matops::scalar::Dual;
+use matops::tape::Tape;
+use matops::Matrix;
+
+/// Directional derivative of `f` at `a` along `b`, by forward-mode AD.
+fn directional<const M: usize>(
+    f: impl Fn(Matrix<Dual<f64>, M, M>) -> Dual<f64>,
+    a: Matrix<f64, M, M>,
+    b: Matrix<f64, M, M>,
+) -> f64 {
+    let mut lifted = Matrix::zeros();
+    for i in 0..M {
+        for j in 0..M {
+            lifted[i][j] = Dual::new(a[i][j], [b[i][j]]);
+        }
+    }
+    f(lifted).derivative()
+}
+
+fn inner<const M: usize>(a: Matrix<f64, M, M>, b: Matrix<f64, M, M>) -> f64 {
+    a.hadamard(&b).data.iter().flatten().sum()
+}
+
+#[test]
+fn test_least_squares_gradient() {
+    let tape = Tape::new();
+    let w = tape.var(Matrix::new([[1.0, -2.0], [0.5, 3.0]]));
+    let x = tape.var(Matrix::new([[2.0], [1.0]]));
+    let y = tape.var(Matrix::new([[1.0], [0.0]]));
+    let residual = w * x - y;
+    let loss = residual.hadamard(&residual).sum();
+    let grads = loss.backward();
+
+    let r = residual.value();
+    let expected = r.kronecker::<1, 2, 2, 2>(&x.value().transpose()) * 2.0;
+    assert_matrix_approx_eq(grads.wrt(&w), expected, 1e-12);
+    assert_matrix_approx_eq(grads.wrt(&x), (w.value().transpose() * r) * 2.0, 1e-12);
+}
+
+#[test]
+fn test_determinant_gradient() {
+    let a: Matrix<f64, 3, 3> = Matrix::new([[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]]);
+    let tape = Tape::new();
+    let var = tape.var(a);
+    let grads = var.determinant().backward();
+    let expected = a.inverse().unwrap().transpose() * a.determinant();
+    assert_matrix_approx_eq(grads.wrt(&var), expected, 1e-9);
+}
+
+#[test]
+fn test_determinant_gradient_of_singular() {
+    let tape = Tape::new();
+    let var = tape.var(Matrix::new([[1.0, 2.0], [2.0, 4.0]]));
+    let grads = var.determinant().backward();
+    assert_matrix_approx_eq(
+        grads.wrt(&var),
+        Matrix::new([[4.0, -2.0], [-2.0, 1.0]]),
+        1e-12,
+    );
+}
+
+#[test]
+fn test_inverse_gradient_matches_forward_mode() {
+    let a: Matrix<f64, 2, 2> = Matrix::new([[4.0, 7.0], [2.0, 6.0]]);
+    let b: Matrix<f64, 2, 2> = Matrix::new([[0.3, -1.0], [2.0, 0.5]]);
+    let c: Matrix<f64, 2, 2> = Matrix::new([[1.0, 2.0], [-1.0, 0.5]]);
+
+    // loss(A) = sum(C ∘ (A⁻¹)ᵀ)
+    let tape = Tape::new();
+    let var = tape.var(a);
+    let weights = tape.var(c);
+    let loss = var.inverse().unwrap().transpose().hadamard(&weights).sum();
+    let gradient = loss.backward().wrt(&var);
+
+    let forward = directional(
+        |m| {
+            let inv = m.inverse().unwrap().transpose();
+            let mut total = Dual::constant(0.0);
+            for i in 0..2 {
+                for j in 0..2 {
+                    total = total + inv[i][j] * Dual::constant(c[i][j]);
+                }
+            }
+            total
+        },
+        a,
+        b,
+    );
+    assert!((inner(gradient, b) - forward).abs() < 1e-9);
+}
+
+#[test]
+fn test_singular_inverse_is_none() {
+    let tape = Tape::new();
+    let var = tape.var(Matrix::new([[1.0, 2.0], [2.0, 4.0]]));
+    assert!(var.inverse().is_none());
+    assert_eq!(tape.len(), 1);
+}
+
+#[test]
+fn test_gradient_descent_fits_linear_model() {
+    // Fit w in y = w x from three samples of y = 3 x - 1 (with a bias column).
+    let xs: Matrix<f64, 3, 2> = Matrix::new([[0.0, 1.0], [1.0, 1.0], [2.0, 1.0]]);
+    let ys: Matrix<f64, 3, 1> = Matrix::new([[-1.0], [2.0], [5.0]]);
+    let mut w: Matrix<f64, 2, 1> = Matrix::zeros();
+    for _ in 0..2000 {
+        let tape = Tape::new();
+        let wv = tape.var(w);
+        let residual = tape.var(xs) * wv - tape.var(ys);
+        let loss = residual.hadamard(&residual).sum();
+        w = w - loss.backward().wrt(&wv) * 0.05;
+    }
+    assert_matrix_approx_eq(w, Matrix::new([[3.0], [-1.0]]), 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "different tapes")]
+fn test_mixing_tapes_panics() {
+    let first = Tape::new();
+    let second = Tape::new();
+    let a = first.var(Matrix::new([[1.0, 2.0]]));
+    let b = second.var(Matrix::new([[3.0, 4.0]]));
+    let _ = a + b;
+}
+
+#[test]
+fn test_gradient_of_later_variable_is_zero() {
+    let tape = Tape::new();
+    let x = tape.var(Matrix::new([[1.0, 2.0], [3.0, 4.0]]));
+    let grads = x.sum().backward();
+    let later = tape.var(Matrix::new([[5.0, 6.0]]));
+    assert_eq!(grads.wrt(&later), Matrix::zeros());
+    assert_eq!(grads.wrt(&x), Matrix::new([[1.0, 1.0], [1.0, 1.0]]));
+}
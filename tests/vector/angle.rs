@@ -0,0 +1,81 @@
+use matops::scalar::{Angle, Deg, Rad};
+use matops::Vector;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+#[test]
+fn test_units_are_explicit() {
+    let v: Vector<f64, 2> = Vector::new([2.0, 1.0]);
+    assert_eq!(v.rotate(Deg(90.0)), v.rotate(Rad(FRAC_PI_2)));
+    assert_eq!(v.rotate(Deg(90.0)), v.rotate(Angle::from_degrees(90.0)));
+    let rotated = v.rotate(Deg(90.0));
+    assert!(rotated.distance(&Vector::new([-1.0, 2.0])) < 1e-12);
+
+    let a = Angle::from(Rad(PI));
+    assert_eq!(a.degrees(), 180.0);
+    assert_eq!(Angle::from(Deg(180.0)).radians(), PI);
+}
+
+#[test]
+fn test_trig() {
+    let a = Angle::from(Deg(60.0_f64));
+    assert!((a.cos() - 0.5).abs() < 1e-12);
+    assert!((a.sin() - 3f64.sqrt() / 2.0).abs() < 1e-12);
+    assert!((a.tan() - 3f64.sqrt()).abs() < 1e-12);
+    assert_eq!(a.sin_cos(), (a.sin(), a.cos()));
+    assert_eq!(Angle::atan2(1.0, 0.0), Angle::from_radians(FRAC_PI_2));
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = Angle::from(Deg(30.0_f64));
+    let b = Angle::from(Deg(45.0_f64));
+    assert!(((a + b).degrees() - 75.0).abs() < 1e-12);
+    assert!(((b - a).degrees() - 15.0).abs() < 1e-12);
+    assert!(((-a).degrees() + 30.0).abs() < 1e-12);
+    assert!(((a * 3.0).degrees() - 90.0).abs() < 1e-12);
+    assert!(((b / 3.0).degrees() - 15.0).abs() < 1e-12);
+    assert!((b / a - 1.5).abs() < 1e-12);
+    let total: Angle<f64> = [a, a, b].iter().copied().sum();
+    assert!((total.degrees() - 105.0).abs() < 1e-12);
+    assert!(a < b);
+}
+
+#[test]
+fn test_wrapping() {
+    let cases: [(f64, f64, f64); 7] = [
+        (0.0, 0.0, 0.0),
+        (180.0, 180.0, 180.0),
+        (-180.0, 180.0, 180.0),
+        (190.0, -170.0, 190.0),
+        (-90.0, -90.0, 270.0),
+        (720.0 + 45.0, 45.0, 45.0),
+        (-1000.0, 80.0, 80.0),
+    ];
+    for &(input, signed, positive) in cases.iter() {
+        let a = Angle::from(Deg(input));
+        assert!(
+            (a.wrap_signed().degrees() - signed).abs() < 1e-9,
+            "{} -> {}",
+            input,
+            a.wrap_signed().degrees()
+        );
+        assert!(
+            (a.wrap_positive().degrees() - positive).abs() < 1e-9,
+            "{} -> {}",
+            input,
+            a.wrap_positive().degrees()
+        );
+    }
+    let tiny = Angle::from_radians(-1e-17_f64).wrap_positive().radians();
+    assert!((0.0..2.0 * PI).contains(&tiny));
+}
+
+#[test]
+fn test_angle_between_returns_angle() {
+    let x: Vector<f64, 3> = Vector::new([1.0, 0.0, 0.0]);
+    let d = Vector::new([1.0, 1.0, 0.0]);
+    assert!((x.angle_between(&d).degrees() - 45.0).abs() < 1e-12);
+    assert!(x.is_orthogonal(&Vector::new([0.0, 0.0, 2.0]), Deg(0.001)));
+    assert!(!x.is_parallel(&d, Deg(44.0)));
+    assert!(x.is_parallel(&d, Deg(46.0)));
+}
@@ -0,0 +1,110 @@
+use matops::vector::basis::LinearDependenceError;
+use matops::{Matrix, Vector};
+
+fn assert_orthonormal<const N: usize, const K: usize>(basis: &[Vector<f64, N>; K], tolerance: f64) {
+    for i in 0..K {
+        for j in 0..K {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            let dot = basis[i].dot(&basis[j]);
+            assert!(
+                (dot - expected).abs() < tolerance,
+                "q{} · q{} = {} (expected {})",
+                i,
+                j,
+                dot,
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn test_orthonormalize_spans_inputs_in_order() {
+    let inputs = [
+        Vector::new([1.0, 1.0, 0.0, 0.0]),
+        Vector::new([1.0, 0.0, 1.0, 0.0]),
+        Vector::new([0.0, 1.0, 1.0, 1.0]),
+    ];
+    let basis = Vector::orthonormalize(inputs).unwrap();
+    assert_orthonormal(&basis, 1e-14);
+    // The first vector keeps its direction.
+    let s = 0.5f64.sqrt();
+    assert!(basis[0].distance(&Vector::new([s, s, 0.0, 0.0])) < 1e-15);
+    // Each input is recovered from its expansion in the basis.
+    for v in inputs.iter() {
+        let rebuilt = basis
+            .iter()
+            .fold(Vector::new([0.0; 4]), |acc, q| acc + *q * q.dot(v));
+        assert!(rebuilt.distance(v) < 1e-14);
+    }
+}
+
+#[test]
+fn test_ill_conditioned_input_stays_orthogonal() {
+    // Columns of the 6x6 Hilbert matrix: classical Gram–Schmidt loses
+    // orthogonality here; projecting twice keeps it at working precision.
+    let hilbert: [Vector<f64, 6>; 6] =
+        core::array::from_fn(|j| Vector::new(core::array::from_fn(|i| 1.0 / (i + j + 1) as f64)));
+    let basis = Vector::orthonormalize(hilbert).unwrap();
+    assert_orthonormal(&basis, 1e-13);
+}
+
+#[test]
+fn test_dependent_inputs_are_reported() {
+    let zero = Vector::orthonormalize([Vector::new([0.0, 0.0]), Vector::new([1.0, 0.0])]);
+    assert_eq!(zero.unwrap_err(), LinearDependenceError { index: 0 });
+
+    let a = Vector::new([1.0, -2.0, 0.5]);
+    let b = Vector::new([0.3, 0.3, 0.3]);
+    let err = Vector::orthonormalize([a, b, a * 2.0 - b * 3.0]).unwrap_err();
+    assert_eq!(err.index, 2);
+    assert_eq!(
+        err.to_string(),
+        "vector 2 is linearly dependent on the preceding vectors"
+    );
+
+    // Nearly dependent but distinguishable inputs are accepted.
+    let almost = a * 2.0 - b * 3.0 + Vector::new([0.0, 0.0, 1e-6]);
+    assert!(Vector::orthonormalize([a, b, almost]).is_ok());
+}
+
+#[test]
+fn test_complete_basis() {
+    let normal = Vector::new([0.0, 0.0, 2.0]);
+    let [n, u, v] = Vector::complete_basis([normal]).unwrap();
+    assert_eq!(n, Vector::new([0.0, 0.0, 1.0]));
+    assert_orthonormal(&[n, u, v], 1e-15);
+
+    let partial = [
+        Vector::new([1.0, 1.0, 1.0, 1.0]),
+        Vector::new([1.0, -1.0, 0.0, 0.0]),
+    ];
+    let full = Vector::complete_basis(partial).unwrap();
+    assert_orthonormal(&full, 1e-14);
+    assert_eq!(&full[..2], &Vector::orthonormalize(partial).unwrap()[..]);
+
+    let standard: [Vector<f64, 3>; 3] = Vector::complete_basis([]).unwrap();
+    assert_orthonormal(&standard, 1e-15);
+    let dependent = Vector::complete_basis([normal, normal * -1.0]);
+    assert_eq!(dependent.unwrap_err().index, 1);
+}
+
+#[test]
+fn test_orthonormalize_columns() {
+    let a: Matrix<f64, 3, 2> = Matrix::new([[2.0, 1.0], [0.0, 1.0], [1.0, 3.0]]);
+    let q = a.orthonormalize_columns().unwrap();
+    let gram = q.transpose() * q;
+    for i in 0..2 {
+        for j in 0..2 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((gram[i][j] - expected).abs() < 1e-14);
+        }
+    }
+    // R = QᵀA is upper triangular, as in a thin QR decomposition.
+    let r = q.transpose() * a;
+    assert!(r[1][0].abs() < 1e-14);
+    assert!(r[0][0] > 0.0 && r[1][1] > 0.0);
+
+    let rank_one = Matrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    assert_eq!(rank_one.orthonormalize_columns().unwrap_err().index, 1);
+}
@@ -0,0 +1,67 @@
+use matops::scalar::fixed::{Q16_16, Q1_31, Q32_32};
+use matops::scalar::{Deg, Real};
+use matops::{Matrix, Vector};
+
+fn q16(x: f64) -> Q16_16 {
+    Q16_16::from_f64(x)
+}
+
+#[test]
+fn test_magnitude_without_floats() {
+    let v = Vector::new([q16(3.0), q16(4.0)]);
+    assert_eq!(v.magnitude(), q16(5.0));
+
+    let w = Vector::new([
+        Q32_32::from_int(1),
+        Q32_32::from_int(1),
+        Q32_32::from_int(1),
+    ]);
+    assert!((w.magnitude().to_f64() - 3f64.sqrt()).abs() < 1e-9);
+}
+
+#[test]
+fn test_rotate_without_floats() {
+    let v = Vector::new([q16(1.0), q16(0.0)]);
+    let rotated = v.rotate(Deg(q16(90.0)));
+    assert!(rotated.data[0].to_f64().abs() < 1e-4);
+    assert!((rotated.data[1].to_f64() - 1.0).abs() < 1e-4);
+
+    let rotated = Vector::new([q16(2.0), q16(1.0)]).rotate(Deg(q16(-30.0)));
+    let expected = Vector::new([2.0f64, 1.0]).rotate(Deg(-30.0));
+    assert!((rotated.data[0].to_f64() - expected.data[0]).abs() < 1e-4);
+    assert!((rotated.data[1].to_f64() - expected.data[1]).abs() < 1e-4);
+}
+
+#[test]
+fn test_fixed_matrix_arithmetic() {
+    let a = Matrix::new([[q16(1.5), q16(-2.0)], [q16(0.25), q16(4.0)]]);
+    let v = Vector::new([q16(2.0), q16(1.0)]);
+    assert_eq!(a * v, Vector::new([q16(1.0), q16(4.5)]));
+    assert!((a.determinant().to_f64() - 6.5).abs() < 1e-3);
+    let inv = a.inverse().unwrap();
+    let product = a * inv;
+    for i in 0..2 {
+        for j in 0..2 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((product[i][j].to_f64() - expected).abs() < 1e-3);
+        }
+    }
+}
+
+#[test]
+fn test_q1_31_signal_gain() {
+    // A 2x2 mixing matrix of gains below one never leaves [-1, 1).
+    let half = Q1_31::from_f64(0.5);
+    let quarter = Q1_31::from_f64(0.25);
+    let mix = Matrix::new([[half, quarter], [quarter, half]]);
+    let sample = Vector::new([Q1_31::from_f64(0.9), Q1_31::from_f64(-0.4)]);
+    let out = mix * sample;
+    assert!((out.data[0].to_f64() - 0.35).abs() < 1e-9);
+    assert!((out.data[1].to_f64() - 0.025).abs() < 1e-9);
+    assert_eq!(Q1_31::from_f64(0.75) + Q1_31::from_f64(0.75), Q1_31::MAX);
+}
+
+#[test]
+fn test_pi() {
+    assert!((Q32_32::pi().to_f64() - core::f64::consts::PI).abs() < 1e-9);
+}
@@ -0,0 +1,160 @@
+use crate::common::assert_vector_approx_eq;
+use matops::scalar::Rad;
+use matops::Vector;
+
+#[test]
+fn test_cross_product_properties() {
+    let a: Vector<i64, 3> = Vector::new([2, -1, 3]);
+    let b: Vector<i64, 3> = Vector::new([4, 0, -2]);
+    let c = a.cross(&b);
+    assert_eq!(c, Vector::new([2, 16, 4]));
+    assert_eq!(c.dot(&a), 0);
+    assert_eq!(c.dot(&b), 0);
+    assert_eq!(b.cross(&a), c * -1);
+    assert_eq!(a.cross(&a), Vector::new([0, 0, 0]));
+}
+
+#[test]
+fn test_integer_magnitude_is_f64() {
+    let v: Vector<i32, 2> = Vector::new([3, -4]);
+    assert_eq!(v.magnitude(), 5.0_f64);
+    let w: Vector<u8, 3> = Vector::new([1, 1, 1]);
+    assert!((w.magnitude() - 3f64.sqrt()).abs() < 1e-15);
+}
+
+#[test]
+fn test_cross_matrix() {
+    let a: Vector<i64, 3> = Vector::new([2, -1, 3]);
+    let b: Vector<i64, 3> = Vector::new([4, 0, -2]);
+    let k = a.cross_matrix();
+    assert_eq!(k * b, a.cross(&b));
+    assert_eq!(k.transpose(), -k);
+}
+
+#[test]
+fn test_triple_products() {
+    let a: Vector<f64, 3> = Vector::new([1.0, 2.0, 3.0]);
+    let b: Vector<f64, 3> = Vector::new([0.0, 1.0, 4.0]);
+    let c: Vector<f64, 3> = Vector::new([5.0, 6.0, 0.0]);
+    // The scalar triple product is the determinant of the rows.
+    assert_eq!(a.scalar_triple(&b, &c), 1.0);
+    assert_eq!(b.scalar_triple(&c, &a), 1.0);
+    assert_eq!(a.scalar_triple(&c, &b), -1.0);
+
+    // BAC-CAB: a × (b × c) = b (a · c) - c (a · b)
+    assert_eq!(a.vector_triple(&b, &c), b * a.dot(&c) - c * a.dot(&b));
+}
+
+#[test]
+fn test_perp_dot() {
+    let a: Vector<f64, 2> = Vector::new([3.0, 1.0]);
+    let b: Vector<f64, 2> = Vector::new([1.0, 2.0]);
+    // Signed area of the parallelogram, positive for a counter-clockwise turn.
+    assert_eq!(a.perp_dot(&b), 5.0);
+    assert_eq!(b.perp_dot(&a), -5.0);
+    let lifted = Vector::new([3.0, 1.0, 0.0]).cross(&Vector::new([1.0, 2.0, 0.0]));
+    assert_eq!(lifted.data[2], a.perp_dot(&b));
+}
+
+#[test]
+fn test_normalize_and_try_normalize() {
+    let v: Vector<f64, 3> = Vector::new([1.0, 2.0, 2.0]);
+    let n = v.normalize().unwrap();
+    assert!((n.magnitude() - 1.0).abs() < 1e-12);
+    assert_vector_approx_eq(n, Vector::new([1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0]), 1e-9);
+
+    assert!(Vector::<f64, 3>::new([0.0; 3]).normalize().is_err());
+    assert!(Vector::new([1e-12, 0.0]).try_normalize(1e-9).is_none());
+    assert!(Vector::new([1e-12, 0.0]).normalize().is_ok());
+}
+
+#[test]
+fn test_distance() {
+    let a: Vector<f64, 3> = Vector::new([1.0, 2.0, 3.0]);
+    let b: Vector<f64, 3> = Vector::new([4.0, 6.0, 3.0]);
+    assert_eq!(a.distance_squared(&b), 25.0);
+    assert_eq!(a.distance(&b), 5.0);
+}
+
+#[test]
+fn test_angle_between() {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    let x: Vector<f64, 2> = Vector::new([1.0, 0.0]);
+    assert!((x.angle_between(&Vector::new([0.0, 3.0])).radians() - FRAC_PI_2).abs() < 1e-12);
+    assert!((x.angle_between(&Vector::new([2.0, 2.0])).radians() - FRAC_PI_4).abs() < 1e-12);
+    assert!((x.angle_between(&Vector::new([-5.0, 0.0])).radians() - PI).abs() < 1e-12);
+    // Kahan's formula resolves tiny angles that acos(dot) would round to zero.
+    let tiny = x.angle_between(&Vector::new([1.0, 1e-10])).radians();
+    assert!((tiny - 1e-10).abs() < 1e-20);
+}
+
+#[test]
+fn test_projection_and_rejection() {
+    let a: Vector<f64, 3> = Vector::new([3.0, 4.0, 5.0]);
+    let b: Vector<f64, 3> = Vector::new([0.0, 2.0, 0.0]);
+    assert_vector_approx_eq(a.project_onto(&b), Vector::new([0.0, 4.0, 0.0]), 1e-9);
+    assert_vector_approx_eq(a.reject_from(&b), Vector::new([3.0, 0.0, 5.0]), 1e-9);
+    assert_vector_approx_eq(
+        a.project_onto(&Vector::new([0.0; 3])),
+        Vector::new([0.0; 3]),
+        1e-9,
+    );
+}
+
+#[test]
+fn test_reflect_and_refract() {
+    let incoming: Vector<f64, 2> = Vector::new([1.0, -1.0]);
+    let up: Vector<f64, 2> = Vector::new([0.0, 2.0]);
+    assert_vector_approx_eq(incoming.reflect(&up), Vector::new([1.0, 1.0]), 1e-9);
+
+    // Snell's law: sin θᵢ = eta sin θₜ with unit vectors.
+    let dir = incoming.normalize().unwrap();
+    let normal = up.normalize().unwrap();
+    let refracted = dir.refract(&normal, 1.0 / 1.5).unwrap();
+    assert!((refracted.magnitude() - 1.0).abs() < 1e-12);
+    let sin_t = refracted.data[0];
+    assert!((dir.data[0] - 1.5 * sin_t).abs() < 1e-12);
+
+    // Going from glass into air at 45° exceeds the critical angle.
+    assert!(dir.refract(&normal, 1.5).is_none());
+}
+
+#[test]
+fn test_lerp_generalizes_halfway() {
+    let a: Vector<f64, 2> = Vector::new([0.0, 10.0]);
+    let b: Vector<f64, 2> = Vector::new([4.0, 2.0]);
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_eq!(a.lerp(&b, 1.0), b);
+    assert_eq!(a.lerp(&b, 0.5), Vector::halfway(&a, &b));
+    assert_eq!(a.lerp(&b, 0.25), Vector::new([1.0, 8.0]));
+    let ints: Vector<i32, 2> = Vector::new([0, 10]);
+    assert_eq!(ints.lerp(&Vector::new([4, 2]), 2), Vector::new([8, -6]));
+}
+
+#[test]
+fn test_centroids() {
+    let points = [
+        Vector::new([0.0, 0.0]),
+        Vector::new([4.0, 0.0]),
+        Vector::new([0.0, 4.0]),
+    ];
+    let c = Vector::centroid(&points).unwrap();
+    assert_vector_approx_eq(c, Vector::new([4.0 / 3.0, 4.0 / 3.0]), 1e-9);
+    let w = Vector::weighted_centroid(&points, &[2.0, 1.0, 1.0]).unwrap();
+    assert_vector_approx_eq(w, Vector::new([1.0, 1.0]), 1e-9);
+    assert!(Vector::<f64, 2>::centroid(&[]).is_none());
+    assert!(Vector::weighted_centroid(&points, &[1.0, -1.0, 0.0]).is_none());
+    assert!(Vector::weighted_centroid(&points, &[1.0, 1.0]).is_none());
+    assert!(Vector::weighted_centroid(&points, &[1.0, 1.0, 1.0, 1.0]).is_none());
+}
+
+#[test]
+fn test_parallel_and_orthogonal() {
+    let a: Vector<f64, 3> = Vector::new([1.0, 2.0, 3.0]);
+    assert!(a.is_parallel(&(a * -2.5), Rad(1e-9)));
+    assert!(!a.is_parallel(&Vector::new([1.0, 2.0, 3.1]), Rad(1e-9)));
+    assert!(a.is_parallel(&Vector::new([1.0, 2.0, 3.0 + 1e-12]), Rad(1e-9)));
+    assert!(a.is_orthogonal(&Vector::new([3.0, 0.0, -1.0]), Rad(1e-9)));
+    assert!(!a.is_orthogonal(&Vector::new([3.0, 0.0, -0.9]), Rad(1e-9)));
+    assert!(a.is_orthogonal(&Vector::new([0.0; 3]), Rad(1e-9)));
+}
@@ -0,0 +1,5 @@
+pub mod angle;
+pub mod basis;
+pub mod fixed;
+pub mod geometry;
+pub mod quaternion;
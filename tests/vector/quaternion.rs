@@ -0,0 +1,135 @@
+use crate::common::assert_vector_approx_eq;
+use matops::scalar::{Quaternion, Rad};
+use matops::{Matrix, Vector};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+fn assert_quaternion_approx_eq(a: Quaternion<f64>, b: Quaternion<f64>) {
+    assert!((a - b).norm() < 1e-9, "Quaternions differ: {} != {}", a, b);
+}
+
+#[test]
+fn test_rotate_vector() {
+    let z: Vector<f64, 3> = Vector::new([0.0, 0.0, 1.0]);
+    let q = Quaternion::from_axis_angle(&z, Rad(FRAC_PI_2)).unwrap();
+    assert_vector_approx_eq(
+        q.rotate(&Vector::new([1.0, 0.0, 0.0])),
+        Vector::new([0.0, 1.0, 0.0]),
+        1e-9,
+    );
+    assert_vector_approx_eq(q.rotate(&z), z, 1e-9);
+
+    // Rotating is conjugation by q.
+    let v = Vector::new([1.0, -2.0, 0.5]);
+    let conj = q * Quaternion::from_parts(0.0, v) * q.inverse().unwrap();
+    assert_vector_approx_eq(q.rotate(&v), conj.vector(), 1e-9);
+}
+
+#[test]
+fn test_composition_matches_matrix_product() {
+    let a = Quaternion::from_axis_angle(&Vector::new([1.0, 2.0, 3.0]), Rad(0.7)).unwrap();
+    let b = Quaternion::from_axis_angle(&Vector::new([-1.0, 0.5, 0.0]), Rad(2.1)).unwrap();
+    let v = Vector::new([0.3, -0.2, 1.5]);
+    assert_vector_approx_eq((a * b).rotate(&v), a.rotate(&b.rotate(&v)), 1e-9);
+
+    let product = a.to_rotation_matrix() * b.to_rotation_matrix();
+    let expected = (a * b).to_rotation_matrix();
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((product.data[i][j] - expected.data[i][j]).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_rotation_matrix_round_trip() {
+    // Exercise every branch of Shepperd's method, including a half turn.
+    let cases = [
+        (Vector::new([0.0, 0.0, 1.0]), 0.3),
+        (Vector::new([1.0, 0.0, 0.0]), PI),
+        (Vector::new([0.0, 1.0, 0.0]), 3.0),
+        (Vector::new([0.1, 0.2, 1.0]), 3.1),
+    ];
+    for &(axis, angle) in cases.iter() {
+        let q = Quaternion::from_axis_angle(&axis, Rad(angle)).unwrap();
+        let back = Quaternion::from_rotation_matrix(&q.to_rotation_matrix());
+        let back = if back.dot(&q) < 0.0 { -back } else { back };
+        assert_quaternion_approx_eq(back, q);
+    }
+    let identity: Matrix<f64, 3, 3> = Matrix::identity();
+    assert_quaternion_approx_eq(
+        Quaternion::from_rotation_matrix(&identity),
+        Quaternion::new(1.0, 0.0, 0.0, 0.0),
+    );
+}
+
+#[test]
+fn test_axis_angle_round_trip() {
+    let axis = Vector::new([2.0, -1.0, 2.0]);
+    let q = Quaternion::from_axis_angle(&axis, Rad(1.2)).unwrap();
+    let (unit, angle) = q.to_axis_angle();
+    assert_vector_approx_eq(unit, Vector::new([2.0 / 3.0, -1.0 / 3.0, 2.0 / 3.0]), 1e-9);
+    assert!((angle.radians() - 1.2).abs() < 1e-12);
+
+    // -q is the same rotation; the angle stays in [0, π].
+    let (unit, angle) = (-q).to_axis_angle();
+    assert_vector_approx_eq(unit, Vector::new([2.0 / 3.0, -1.0 / 3.0, 2.0 / 3.0]), 1e-9);
+    assert!((angle.radians() - 1.2).abs() < 1e-12);
+
+    assert!(Quaternion::from_axis_angle(&Vector::new([0.0; 3]), Rad(1.0)).is_err());
+}
+
+#[test]
+fn test_from_two_vectors() {
+    let a = Vector::new([1.0, 2.0, 3.0]);
+    let b = Vector::new([-3.0, 0.5, 2.0]);
+    let q = Quaternion::from_two_vectors(&a, &b).unwrap();
+    let rotated = q.rotate(&a.normalize().unwrap());
+    assert_vector_approx_eq(rotated, b.normalize().unwrap(), 1e-9);
+    // Shortest arc: the rotation angle is the angle between the vectors.
+    assert!((q.to_axis_angle().1 - a.angle_between(&b)).radians().abs() < 1e-9);
+
+    let opposite = Quaternion::from_two_vectors(&a, &(a * -2.0)).unwrap();
+    assert_vector_approx_eq(opposite.rotate(&a), a * -1.0, 1e-9);
+
+    let same = Quaternion::from_two_vectors(&a, &(a * 4.0)).unwrap();
+    assert_quaternion_approx_eq(same, Quaternion::new(1.0, 0.0, 0.0, 0.0));
+    assert!(Quaternion::from_two_vectors(&a, &Vector::new([0.0; 3])).is_err());
+}
+
+#[test]
+fn test_slerp_and_nlerp() {
+    let z = Vector::new([0.0, 0.0, 1.0]);
+    let a = Quaternion::from_axis_angle(&z, Rad(0.2)).unwrap();
+    let b = Quaternion::from_axis_angle(&z, Rad(1.8)).unwrap();
+    assert_quaternion_approx_eq(a.slerp(&b, 0.0), a);
+    assert_quaternion_approx_eq(a.slerp(&b, 1.0), b);
+    for &t in [0.25, 0.5, 0.9].iter() {
+        let expected = Quaternion::from_axis_angle(&z, Rad(0.2 + 1.6 * t)).unwrap();
+        assert_quaternion_approx_eq(a.slerp(&b, t), expected);
+    }
+    // Halfway is the same for both, but nlerp does not keep constant speed.
+    assert_quaternion_approx_eq(a.nlerp(&b, 0.5), a.slerp(&b, 0.5));
+    let quarter = a.nlerp(&b, 0.25);
+    assert!((quarter.norm() - 1.0).abs() < 1e-12);
+    assert!((quarter.to_axis_angle().1.radians() - 0.6).abs() > 1e-3);
+
+    // The shorter arc is taken even when the inputs lie in opposite hemispheres.
+    assert_quaternion_approx_eq(a.slerp(&-b, 0.5), a.slerp(&b, 0.5));
+    assert_quaternion_approx_eq(a.slerp(&a, 0.3), a);
+}
+
+#[test]
+fn test_exp_log() {
+    let v: Vector<f64, 3> = Vector::new([0.3, -0.4, 1.2]);
+    let q = Quaternion::exp(&v);
+    assert!((q.norm() - 1.0).abs() < 1e-12);
+    assert_vector_approx_eq(q.log(), v, 1e-9);
+    // exp(θ/2 n) is the rotation by θ about n.
+    let n = v.normalize().unwrap();
+    let expected = Quaternion::from_axis_angle(&n, Rad(2.0 * v.magnitude())).unwrap();
+    assert_quaternion_approx_eq(q, expected);
+    assert_eq!(
+        Quaternion::exp(&Vector::new([0.0; 3])),
+        Quaternion::new(1.0, 0.0, 0.0, 0.0)
+    );
+}